@@ -0,0 +1,193 @@
+use crate::simulation::DynSimulation;
+use std::io::Write;
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+/// Frame rates can be changed at runtime, but stay within these bounds.
+pub const MIN_FPS: u32 = 1;
+pub const MAX_FPS: u32 = 1000;
+
+/// What the keys pressed during an animation do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    TogglePause,
+    /// Advances a paused animation by a single step
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Command {
+    /// Space pauses, `n` steps, `+` and `-` change the speed and `q` quits.
+    pub fn from_key(key: u8) -> Option<Self> {
+        match key {
+            b' ' | b'p' => Some(Command::TogglePause),
+            b'n' | b's' => Some(Command::Step),
+            b'+' | b'=' => Some(Command::Faster),
+            b'-' => Some(Command::Slower),
+            b'q' => Some(Command::Quit),
+            _ => None,
+        }
+    }
+}
+
+pub const KEY_HELP: &str = "space: pause, n: step, +/-: speed, q: quit";
+
+#[derive(Debug, Clone, Copy)]
+pub struct Playback {
+    pub fps: u32,
+    pub step_limit: Option<usize>,
+    pub start_paused: bool,
+}
+
+/// Plays `simulation` on `out` using ANSI escape codes, redrawing it in place after every step.
+/// Stops once the simulation is done, the step limit is reached or [`Command::Quit`] is
+/// received. Returns the number of steps taken.
+pub fn animate(
+    simulation: &mut dyn DynSimulation,
+    playback: Playback,
+    commands: &Receiver<Command>,
+    mut out: impl Write,
+) -> std::io::Result<usize> {
+    let mut fps = playback.fps.clamp(MIN_FPS, MAX_FPS);
+    let mut paused = playback.start_paused;
+    let mut steps = 0;
+    // Without anybody sending commands there is nothing to wait for while paused
+    let mut commands_open = true;
+
+    // Clear the screen and hide the cursor
+    write!(out, "\x1b[2J\x1b[?25l")?;
+    let res = loop {
+        draw(&mut out, simulation, steps, fps, paused)?;
+
+        let finished = simulation.is_done() || playback.step_limit.is_some_and(|n| steps >= n);
+        if finished {
+            break Ok(steps);
+        }
+
+        let frame_time = Duration::from_secs(1) / fps;
+        let command = if paused && commands_open {
+            commands.recv().ok()
+        } else if commands_open {
+            match commands.recv_timeout(frame_time) {
+                Ok(command) => Some(command),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => {
+                    commands_open = false;
+                    None
+                }
+            }
+        } else {
+            std::thread::sleep(frame_time);
+            None
+        };
+        if command.is_none() && paused {
+            // The sender hung up while paused, play to the end
+            commands_open = false;
+            paused = false;
+            continue;
+        }
+
+        match command {
+            None => {
+                simulation.step();
+                steps += 1;
+            }
+            Some(Command::Step) => {
+                if paused {
+                    simulation.step();
+                    steps += 1;
+                }
+            }
+            Some(Command::TogglePause) => paused = !paused,
+            Some(Command::Faster) => fps = (fps * 2).min(MAX_FPS),
+            Some(Command::Slower) => fps = (fps / 2).max(MIN_FPS),
+            Some(Command::Quit) => break Ok(steps),
+        }
+    };
+
+    // Show the cursor again
+    write!(out, "\x1b[?25h")?;
+    out.flush()?;
+    res
+}
+
+fn draw(
+    out: &mut impl Write,
+    simulation: &dyn DynSimulation,
+    steps: usize,
+    fps: u32,
+    paused: bool,
+) -> std::io::Result<()> {
+    // Move the cursor to the top left and draw over the previous frame
+    write!(out, "\x1b[H{}", simulation.render())?;
+    let state = if paused { "paused" } else { "playing" };
+    let status = simulation.status();
+    let separator = if status.is_empty() { "" } else { ", " };
+    // Clear the rest of the status line, it may have been longer before
+    writeln!(
+        out,
+        "step {steps}{separator}{status}\x1b[K\n{state} at {fps} fps ({KEY_HELP})\x1b[K"
+    )?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::Part;
+    use std::sync::mpsc::channel;
+
+    const EXAMPLE: &str = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    #[test]
+    fn steps_through_paused_animation() {
+        let mut simulation = days::simulation(15, EXAMPLE, Part::A).unwrap().unwrap();
+        let (sender, commands) = channel();
+        for command in [Command::Step, Command::Step, Command::Quit] {
+            sender.send(command).unwrap();
+        }
+
+        let mut out = Vec::new();
+        let playback = Playback {
+            fps: 10,
+            step_limit: None,
+            start_paused: true,
+        };
+        let steps = animate(simulation.as_mut(), playback, &commands, &mut out).unwrap();
+        assert_eq!(steps, 2);
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("step 2, robot at 2,1, 2 of 15 moves done, 0 pushes"));
+        assert!(out.contains("#.@O.O.#"));
+        assert!(out.ends_with("\x1b[?25h"));
+    }
+
+    #[test]
+    fn plays_to_the_end_without_commands() {
+        let mut simulation = days::simulation(15, EXAMPLE, Part::A).unwrap().unwrap();
+        let (sender, commands) = channel();
+        sender.send(Command::Faster).unwrap();
+        drop(sender);
+
+        let playback = Playback {
+            fps: MAX_FPS,
+            step_limit: Some(10),
+            start_paused: false,
+        };
+        let steps = animate(simulation.as_mut(), playback, &commands, std::io::sink()).unwrap();
+        assert_eq!(steps, 10);
+    }
+}
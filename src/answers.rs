@@ -0,0 +1,205 @@
+use crate::days::{self, RunResult};
+use crate::settings::Settings;
+use crate::{InputLine, InputSource, ParseError, ParseErrorKind, Part};
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+/// Default location of the answer registry, relative to the working directory.
+pub const ANSWERS_PATH: &str = "answers.txt";
+
+/// A known-good answer for one part of a day on a specific input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    /// `None` if the input has no answer for the part
+    pub expected: Option<u64>,
+    /// Rules the answer was found with, needed where an example differs from the real puzzle
+    pub settings: Settings,
+}
+
+impl Answer {
+    /// The expected answer, or `none` if the input has no answer.
+    pub fn expected_text(&self) -> String {
+        self.expected
+            .map_or_else(|| "none".to_owned(), |expected| expected.to_string())
+    }
+}
+
+/// Parses the answer registry. Every non-empty line that doesn't start with `#` has the form
+/// `<day> <part> <input path> <expected answer> [<key>=<value>...]`. The expected answer is `none`
+/// if the input has no answer, the settings are passed to the day.
+pub fn parse_answers(input: &str) -> Result<Vec<Answer>, ParseError> {
+    InputLine::enumerate(input)
+        .filter(|line| {
+            let text = line.text.trim();
+            !text.is_empty() && !text.starts_with('#')
+        })
+        .map(|line| {
+            let mut tokens = line.text.split_whitespace();
+            let day = line.parse_number(line.expect_token(tokens.next(), "day")?)?;
+            let part_token = line.expect_token(tokens.next(), "part")?;
+            let part = part_token.parse().map_err(|_| {
+                line.error(part_token, ParseErrorKind::UnexpectedToken("'a' or 'b'"))
+            })?;
+            let input = line.expect_token(tokens.next(), "input path")?.into();
+            let expected = match line.expect_token(tokens.next(), "expected answer")? {
+                "none" => None,
+                token => Some(line.parse_number(token)?),
+            };
+
+            let mut settings = Settings::default();
+            for token in tokens {
+                let (key, value) = token.split_once('=').ok_or_else(|| {
+                    line.error(token, ParseErrorKind::UnexpectedToken("'<key>=<value>'"))
+                })?;
+                settings.set(key, value);
+            }
+
+            Ok(Answer {
+                day,
+                part,
+                input,
+                expected,
+                settings,
+            })
+        })
+        .collect()
+}
+
+/// Outcome of checking a single registry entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        /// `None` if the input has no answer for the part
+        actual: Option<u64>,
+    },
+    /// The input file isn't available, e.g. because puzzle inputs aren't checked in.
+    MissingInput,
+    /// The input couldn't be read or parsed, or there is no solution for the day.
+    Error(String),
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Fail { .. } | Verdict::Error(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::MissingInput => write!(f, "skipped (no input)"),
+            Verdict::Error(err) => write!(f, "ERROR: {err}"),
+        }
+    }
+}
+
+/// Runs the solutions for all given answers and compares the results. Every input is only parsed
+/// once per settings, even if answers for both parts are registered.
+pub fn verify(answers: &[Answer]) -> Vec<(&Answer, Verdict)> {
+    let run_key = |answer: &'_ Answer| (answer.day, answer.input.clone(), answer.settings.clone());
+    let by_run = answers.iter().into_group_map_by(|answer| run_key(answer));
+
+    answers
+        .iter()
+        .map(run_key)
+        .unique()
+        .flat_map(|key| {
+            let answers = &by_run[&key];
+            let (day, input, settings) = key;
+            let parts = answers.iter().map(|answer| answer.part).collect::<Vec<_>>();
+            let results = run_on_file(day, &input, &parts, &settings);
+
+            answers.iter().map(move |&answer| {
+                let verdict = match &results {
+                    Ok(Some(result)) => {
+                        // All requested parts are solved, so `None` means there is no answer
+                        let actual = result.answer(answer.part);
+                        if actual == answer.expected {
+                            Verdict::Pass
+                        } else {
+                            Verdict::Fail { actual }
+                        }
+                    }
+                    Ok(None) => Verdict::MissingInput,
+                    Err(err) => Verdict::Error(err.clone()),
+                };
+                (answer, verdict)
+            })
+        })
+        .collect()
+}
+
+fn run_on_file(
+    day: u8,
+    input: &Path,
+    parts: &[Part],
+    settings: &Settings,
+) -> Result<Option<RunResult>, String> {
+    if !input.is_file() {
+        return Ok(None);
+    }
+    days::check_settings(day, settings)?;
+
+    let input = InputSource::File(input.to_owned())
+        .load()
+        .map_err(|e| e.to_string())?;
+    match days::run_with(day, &input, parts, settings) {
+        Some(Ok(result)) => Ok(Some(result)),
+        Some(Err(e)) => Err(e.to_string()),
+        None => Err(format!("no solution for day {day}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_registry() {
+        let answers = parse_answers(
+            "# day part input expected\n\n9 a inputs/examples/day09.txt 1928\n 14 b x.txt none width=11\n",
+        )
+        .unwrap();
+        let mut settings = Settings::default();
+        settings.set("width", "11");
+
+        assert_eq!(
+            answers,
+            [
+                Answer {
+                    day: 9,
+                    part: Part::A,
+                    input: "inputs/examples/day09.txt".into(),
+                    expected: Some(1928),
+                    settings: Settings::default(),
+                },
+                Answer {
+                    day: 14,
+                    part: Part::B,
+                    input: "x.txt".into(),
+                    expected: None,
+                    settings,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        let err = parse_answers("9 c x.txt 1\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+
+        let err = parse_answers("9 a x.txt\n").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::MissingToken("expected answer"));
+
+        let err = parse_answers("14 a x.txt 12 width\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 15));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::days::{self, RunResult};
+use crate::input::InputError;
+use crate::settings::Settings;
+use crate::{InputSource, ParseError, Part};
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// What happened when running a day on one of the inputs of a batch.
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(RunResult),
+    ReadFailed(InputError),
+    ParseFailed(ParseError),
+    /// The solution panicked, contains the panic message if it was a string.
+    Panicked(String),
+}
+
+impl Display for Outcome {
+    /// Describes failures, solved runs are just reported as `ok`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Solved(_) => write!(f, "ok"),
+            Outcome::ReadFailed(e) => write!(f, "{e}"),
+            Outcome::ParseFailed(e) => write!(f, "parse error: {e}"),
+            Outcome::Panicked(msg) => write!(f, "panicked: {msg}"),
+        }
+    }
+}
+
+/// All regular, non-hidden files in `dir`, sorted by name.
+pub fn input_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    files.retain(|path| {
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        path.is_file() && !hidden
+    });
+    files.sort();
+    Ok(files)
+}
+
+/// Runs `day` with `settings` on every input in parallel and returns the outcomes in the order of
+/// `inputs`.
+/// Returns `None` if there is no solution for that day.
+pub fn run_batch(
+    day: u8,
+    inputs: &[PathBuf],
+    parts: &[Part],
+    settings: &Settings,
+) -> Option<Vec<Outcome>> {
+    if !days::DAYS.contains(&day) {
+        return None;
+    }
+
+    let num_threads = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(inputs.len());
+    let next_input = AtomicUsize::new(0);
+    let outcomes = Mutex::new(inputs.iter().map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..num_threads {
+            scope.spawn(|| loop {
+                let idx = next_input.fetch_add(1, Ordering::Relaxed);
+                let Some(path) = inputs.get(idx) else {
+                    break;
+                };
+                let outcome = run_one(day, path, parts, settings);
+                outcomes
+                    .lock()
+                    .expect("no thread panics while holding the lock")[idx] = Some(outcome);
+            });
+        }
+    });
+
+    Some(
+        outcomes
+            .into_inner()
+            .expect("no thread panics while holding the lock")
+            .into_iter()
+            .map(|outcome| outcome.expect("every input was run"))
+            .collect(),
+    )
+}
+
+fn run_one(day: u8, path: &Path, parts: &[Part], settings: &Settings) -> Outcome {
+    let input = match InputSource::File(path.to_owned()).load() {
+        Ok(input) => input,
+        Err(e) => return Outcome::ReadFailed(e),
+    };
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        days::run_with(day, &input, parts, settings)
+    })) {
+        Ok(Some(Ok(result))) => Outcome::Solved(result),
+        Ok(Some(Err(e))) => Outcome::ParseFailed(e),
+        Ok(None) => unreachable!("day {day} was checked to have a solution"),
+        Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+    }
+}
+
+/// The message of a caught panic if it was a string.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|msg| msg.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown cause".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_failing_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc24-batch-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("1-good.txt"), "2333133121414131402\n").unwrap();
+        std::fs::write(dir.join("2-bad.txt"), "23x3\n").unwrap();
+        std::fs::write(dir.join(".hidden"), "").unwrap();
+
+        let files = input_files(&dir).unwrap();
+        let outcomes = run_batch(9, &files, &Part::ALL, &Settings::default()).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(files.len(), 2);
+        let Outcome::Solved(result) = &outcomes[0] else {
+            panic!("expected a solved run, got {:?}", outcomes[0]);
+        };
+        assert_eq!(result.answer(Part::A), Some(1928));
+        assert_eq!(result.answer(Part::B), Some(2858));
+        assert!(matches!(&outcomes[1], Outcome::ParseFailed(e) if e.column == 3));
+
+        assert!(run_batch(0, &files, &Part::ALL, &Settings::default()).is_none());
+    }
+}
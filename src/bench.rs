@@ -0,0 +1,157 @@
+use crate::days;
+use crate::json::Json;
+use crate::{ParseError, Part};
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+/// Summary of the timings of many iterations of the same phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Computes the statistics with the nearest-rank method. Panics if there are no samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to compute statistics of");
+        samples.sort_unstable();
+
+        let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Stats {
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+
+    pub fn to_json(&self) -> Json {
+        Json::object([
+            ("min_ms", millis(self.min).into()),
+            ("median_ms", millis(self.median).into()),
+            ("p95_ms", millis(self.p95).into()),
+        ])
+    }
+}
+
+/// Milliseconds with microsecond precision, more digits are just noise.
+pub fn millis(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+/// A separately timed part of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::Solve(part) => f.pad(&format!("part {part}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayBench {
+    pub day: u8,
+    pub iterations: usize,
+    pub phases: Vec<(Phase, Stats)>,
+}
+
+impl DayBench {
+    /// Serializes the results, `input` describes where the benchmarked input came from.
+    pub fn to_json(&self, input: &str) -> Json {
+        let phases = self.phases.iter().map(|(phase, stats)| {
+            let key = match phase {
+                Phase::Parse => "parse".to_owned(),
+                Phase::Solve(part) => part.to_string(),
+            };
+            (key, stats.to_json())
+        });
+
+        Json::object(
+            [
+                ("day".to_owned(), self.day.into()),
+                ("input".to_owned(), input.into()),
+                ("iterations".to_owned(), self.iterations.into()),
+            ]
+            .into_iter()
+            .chain(phases),
+        )
+    }
+}
+
+/// Parses and solves both parts of `day` `iterations` times and summarizes how long each phase
+/// took. Returns `None` if there is no solution for that day.
+pub fn bench_day(day: u8, input: &str, iterations: usize) -> Option<Result<DayBench, ParseError>> {
+    assert!(iterations > 0, "at least one iteration is needed");
+
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut part_samples = Part::ALL.map(|_| Vec::with_capacity(iterations));
+
+    for _ in 0..iterations {
+        let result = match days::run(day, input, &Part::ALL)? {
+            Ok(result) => result,
+            Err(e) => return Some(Err(e)),
+        };
+
+        parse_samples.push(result.parse_time);
+        for (samples, part_result) in part_samples.iter_mut().zip(&result.parts) {
+            samples.push(part_result.solve_time);
+        }
+    }
+
+    let phases = std::iter::once((Phase::Parse, Stats::from_samples(parse_samples)))
+        .chain(
+            Part::ALL
+                .into_iter()
+                .zip(part_samples)
+                .map(|(part, samples)| (Phase::Solve(part), Stats::from_samples(samples))),
+        )
+        .collect();
+
+    Some(Ok(DayBench {
+        day,
+        iterations,
+        phases,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_rank_statistics() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(10));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+
+        let single = Stats::from_samples(vec![Duration::from_millis(7)]);
+        assert_eq!(single.p95, Duration::from_millis(7));
+    }
+
+    #[test]
+    fn benches_all_phases() {
+        let bench = bench_day(9, "2333133121414131402\n", 3).unwrap().unwrap();
+        let phases = bench
+            .phases
+            .iter()
+            .map(|(phase, _)| *phase)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            phases,
+            [Phase::Parse, Phase::Solve(Part::A), Phase::Solve(Part::B)]
+        );
+        assert!(bench_day(0, "", 1).is_none());
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{InputLine, ParseError, ParseErrorKind};
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// What separates the fields of a row.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Delimiter {
+    /// Any amount of spaces and tabs, like the puzzle inputs
+    #[default]
+    Whitespace,
+    Comma,
+    Tab,
+}
+
+impl Delimiter {
+    /// The fields of `text`. Fields separated by commas or tabs may be padded with spaces.
+    fn split(self, text: &str) -> Vec<&str> {
+        match self {
+            Delimiter::Whitespace => text.split_whitespace().collect(),
+            Delimiter::Comma => text.split(',').map(str::trim).collect(),
+            Delimiter::Tab => text.split('\t').map(str::trim).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ColumnFormat {
+    pub delimiter: Delimiter,
+    /// Skip the first line, which names the columns
+    pub header: bool,
+}
+
+impl ColumnFormat {
+    pub fn new(delimiter: Delimiter) -> Self {
+        ColumnFormat {
+            delimiter,
+            header: false,
+        }
+    }
+
+    pub fn with_header(self) -> Self {
+        ColumnFormat {
+            header: true,
+            ..self
+        }
+    }
+}
+
+/// A row of typed fields, implemented for tuples of up to four types that can be parsed from
+/// their field, like `(u64, i64)`.
+pub trait Row: Sized {
+    /// Number of fields every row must have
+    const LEN: usize;
+    /// A `Vec` per field
+    type Columns: Default;
+
+    /// Parses exactly [`Self::LEN`] fields of `line`.
+    fn parse(line: &InputLine, fields: &[&str]) -> Result<Self, ParseError>;
+
+    fn push_to(self, columns: &mut Self::Columns);
+}
+
+macro_rules! impl_row {
+    ($len:literal; $($field:ident $idx:tt),+) => {
+        impl<$($field: FromStr),+> Row for ($($field,)+) {
+            const LEN: usize = $len;
+            type Columns = ($(Vec<$field>,)+);
+
+            fn parse(line: &InputLine, fields: &[&str]) -> Result<Self, ParseError> {
+                Ok(($(line.parse_number::<$field>(fields[$idx])?,)+))
+            }
+
+            fn push_to(self, columns: &mut Self::Columns) {
+                $(columns.$idx.push(self.$idx);)+
+            }
+        }
+    };
+}
+
+impl_row!(1; A 0);
+impl_row!(2; A 0, B 1);
+impl_row!(3; A 0, B 1, C 2);
+impl_row!(4; A 0, B 1, C 2, D 3);
+
+/// Parses every line into a row of `R` and returns the values column by column. Rows with more or
+/// fewer fields than `R` has are rejected.
+pub fn parse_columns<R: Row>(
+    reader: impl BufRead,
+    format: ColumnFormat,
+) -> Result<R::Columns, ParseError> {
+    parse_rows::<R>(reader.lines(), format).try_fold(R::Columns::default(), |mut columns, row| {
+        row?.push_to(&mut columns);
+        Ok(columns)
+    })
+}
+
+/// Parses one row at a time, for inputs that are too large to keep all their columns in memory.
+pub fn parse_rows<R: Row>(
+    lines: impl Iterator<Item = std::io::Result<String>>,
+    format: ColumnFormat,
+) -> impl Iterator<Item = Result<R, ParseError>> {
+    lines
+        .enumerate()
+        .skip(usize::from(format.header))
+        .map(move |(idx, line_res)| {
+            let text = line_res.map_err(|e| ParseError::io(idx + 1, e))?;
+            let line = InputLine::new(idx + 1, &text);
+            let fields = format.delimiter.split(&text);
+            if fields.len() != R::LEN {
+                let kind = ParseErrorKind::ColumnCount {
+                    expected: R::LEN,
+                    found: fields.len(),
+                };
+                return Err(match fields.get(R::LEN) {
+                    Some(extra) => line.error(extra, kind),
+                    None => ParseError::new(kind, line.number, line.end_column(), ""),
+                });
+            }
+
+            R::parse(&line, &fields)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_typed_columns() {
+        let (a, b) =
+            parse_columns::<(u64, u64)>("3   4\n4\t 3\n".as_bytes(), Default::default()).unwrap();
+        assert_eq!(a, [3, 4]);
+        assert_eq!(b, [4, 3]);
+
+        let csv = "id, delta, name\n1, -4, a\n2,7,b\n";
+        let format = ColumnFormat::new(Delimiter::Comma).with_header();
+        let (ids, deltas, names) =
+            parse_columns::<(u8, i64, String)>(csv.as_bytes(), format).unwrap();
+        assert_eq!(ids, [1, 2]);
+        assert_eq!(deltas, [-4, 7]);
+        assert_eq!(names, ["a", "b"]);
+
+        let (single,) =
+            parse_columns::<(i32,)>("-1\n2\n".as_bytes(), ColumnFormat::new(Delimiter::Tab))
+                .unwrap();
+        assert_eq!(single, [-1, 2]);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let err =
+            parse_columns::<(u64, u64)>("3   4\n4\n".as_bytes(), Default::default()).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::ColumnCount {
+                expected: 2,
+                found: 1
+            }
+        );
+
+        let err =
+            parse_columns::<(u64, u64)>("3   4  5\n".as_bytes(), Default::default()).unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 8, "5"));
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 8: expected 2 columns, found 3"
+        );
+
+        let format = ColumnFormat::new(Delimiter::Comma);
+        let err = parse_columns::<(i64, i64)>("1,\n".as_bytes(), format).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+        assert_eq!(err.kind, ParseErrorKind::InvalidNumber);
+
+        let err =
+            parse_columns::<(u64, u64)>("3   -4\n".as_bytes(), Default::default()).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(err.kind, ParseErrorKind::InvalidNumber);
+    }
+}
@@ -0,0 +1,408 @@
+use crate::columns::{parse_columns, parse_rows, ColumnFormat};
+use crate::days::DayReport;
+use crate::external::{ExternalError, ExternalSorter, SortedRuns};
+use crate::json::Json;
+use crate::{ParseError, Solution};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<u64>, Vec<u64>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_columns::<(u64, u64)>(input.as_bytes(), ColumnFormat::default())
+    }
+
+    fn part_a((a, b): &Self::Input) -> Option<u64> {
+        Some(calculate_total_diff(a, b))
+    }
+
+    fn part_b((a, b): &Self::Input) -> Option<u64> {
+        Some(calculate_similarity(a, b))
+    }
+}
+
+fn calculate_total_diff(a: &[u64], b: &[u64]) -> u64 {
+    let a = a.iter().copied().sorted();
+    let b = b.iter().copied().sorted();
+
+    a.zip(b).map(|(a, b)| a.abs_diff(b)).sum()
+}
+
+fn calculate_similarity(a: &[u64], b: &[u64]) -> u64 {
+    let b_count_lookup = b.iter().copied().counts();
+
+    a.iter()
+        .map(|&a| {
+            let b_count = *b_count_lookup.get(&a).unwrap_or(&0usize) as u64;
+            a * b_count
+        })
+        .sum()
+}
+
+/// Both lists of an input that is too large to keep in memory, sorted on disk. Gives the same
+/// answers as [`Day01`].
+pub struct ExternalLists {
+    left: SortedRuns,
+    right: SortedRuns,
+}
+
+impl ExternalLists {
+    /// Parses the lists line by line, keeping at most `run_len` numbers of each list in memory and
+    /// spilling sorted runs into `temp_dir`.
+    pub fn spill(
+        lines: impl Iterator<Item = std::io::Result<String>>,
+        temp_dir: &Path,
+        run_len: usize,
+    ) -> Result<Self, ExternalError> {
+        let mut left = ExternalSorter::new(temp_dir, run_len)?;
+        let mut right = ExternalSorter::new(temp_dir, run_len)?;
+        for row in parse_rows::<(u64, u64)>(lines, ColumnFormat::default()) {
+            let (a, b) = row?;
+            left.push(a)?;
+            right.push(b)?;
+        }
+
+        Ok(ExternalLists {
+            left: left.finish()?,
+            right: right.finish()?,
+        })
+    }
+
+    /// Answer of part a, pairing up the numbers of both sorted lists while reading them.
+    pub fn total_distance(&self) -> std::io::Result<u64> {
+        self.left
+            .iter()?
+            .zip(self.right.iter()?)
+            .try_fold(0, |total, (a, b)| Ok(total + a?.abs_diff(b?)))
+    }
+
+    /// Answer of part b. Both lists are read in order side by side, so only the counts of the
+    /// current value are needed instead of a lookup of all counts.
+    pub fn similarity(&self) -> std::io::Result<u64> {
+        let mut left = self.left.iter()?.peekable();
+        let mut right = self.right.iter()?.peekable();
+        let mut total = 0;
+        while let Some(value) = left.next().transpose()? {
+            let mut left_count = 1;
+            while left
+                .next_if(|next| matches!(next, Ok(next) if *next == value))
+                .is_some()
+            {
+                left_count += 1;
+            }
+
+            // Errors are taken as well to be returned here
+            let mut right_count = 0;
+            while let Some(next) = right.next_if(|next| !matches!(next, Ok(next) if *next > value))
+            {
+                if next? == value {
+                    right_count += 1;
+                }
+            }
+
+            total += value * left_count * right_count;
+        }
+        Ok(total)
+    }
+}
+
+/// Why the total distance and similarity are what they are, see [`report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// Both lists sorted and paired up, as part a compares them
+    pub pairs: Vec<Pair>,
+    /// Indices into `pairs` of the largest distances, largest first
+    pub largest_gaps: Vec<usize>,
+    /// Distinct values that only appear in the left list, in ascending order
+    pub only_left: Vec<u64>,
+    /// Distinct values that only appear in the right list, in ascending order
+    pub only_right: Vec<u64>,
+    /// Every distinct value of both lists in ascending order
+    pub similarity: Vec<Similarity>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pair {
+    pub left: u64,
+    pub right: u64,
+    pub distance: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Similarity {
+    pub value: u64,
+    pub left_count: u64,
+    pub right_count: u64,
+    /// What the value adds to the similarity score, `value * left_count * right_count`
+    pub contribution: u64,
+}
+
+/// Breaks both answers down into the contributing pairs and values, keeping the `top_k` largest
+/// gaps. Ties between gaps are broken by position.
+pub fn report(a: &[u64], b: &[u64], top_k: usize) -> Report {
+    let pairs = a
+        .iter()
+        .copied()
+        .sorted()
+        .zip(b.iter().copied().sorted())
+        .map(|(left, right)| Pair {
+            left,
+            right,
+            distance: left.abs_diff(right),
+        })
+        .collect::<Vec<_>>();
+    let largest_gaps = (0..pairs.len())
+        .sorted_by_key(|&idx| std::cmp::Reverse(pairs[idx].distance))
+        .take(top_k)
+        .collect();
+
+    let mut counts = BTreeMap::<u64, (u64, u64)>::new();
+    for &value in a {
+        counts.entry(value).or_default().0 += 1;
+    }
+    for &value in b {
+        counts.entry(value).or_default().1 += 1;
+    }
+
+    let only_left = counts
+        .iter()
+        .filter(|(_, &(_, right_count))| right_count == 0)
+        .map(|(&value, _)| value)
+        .collect();
+    let only_right = counts
+        .iter()
+        .filter(|(_, &(left_count, _))| left_count == 0)
+        .map(|(&value, _)| value)
+        .collect();
+    let similarity = counts
+        .into_iter()
+        .map(|(value, (left_count, right_count))| Similarity {
+            value,
+            left_count,
+            right_count,
+            contribution: value * left_count * right_count,
+        })
+        .collect();
+
+    Report {
+        pairs,
+        largest_gaps,
+        only_left,
+        only_right,
+        similarity,
+    }
+}
+
+impl Report {
+    pub fn total_distance(&self) -> u64 {
+        self.pairs.iter().map(|pair| pair.distance).sum()
+    }
+
+    pub fn total_similarity(&self) -> u64 {
+        self.similarity.iter().map(|entry| entry.contribution).sum()
+    }
+}
+
+impl DayReport for Report {
+    fn to_json(&self) -> Json {
+        let pair_json = |pair: &Pair| {
+            Json::object([
+                ("left", pair.left.into()),
+                ("right", pair.right.into()),
+                ("distance", pair.distance.into()),
+            ])
+        };
+        let values = |values: &[u64]| Json::array(values.iter().map(|&value| value.into()));
+
+        Json::object([
+            ("total_distance", self.total_distance().into()),
+            ("total_similarity", self.total_similarity().into()),
+            ("pairs", Json::array(self.pairs.iter().map(pair_json))),
+            (
+                "largest_gaps",
+                Json::array(self.largest_gaps.iter().map(|&idx| {
+                    let Json::Object(mut fields) = pair_json(&self.pairs[idx]) else {
+                        unreachable!("pairs are objects");
+                    };
+                    fields.insert(0, ("index".to_owned(), idx.into()));
+                    Json::Object(fields)
+                })),
+            ),
+            ("only_left", values(&self.only_left)),
+            ("only_right", values(&self.only_right)),
+            (
+                "similarity",
+                Json::array(self.similarity.iter().map(|entry| {
+                    Json::object([
+                        ("value", entry.value.into()),
+                        ("left_count", entry.left_count.into()),
+                        ("right_count", entry.right_count.into()),
+                        ("contribution", entry.contribution.into()),
+                    ])
+                })),
+            ),
+        ])
+    }
+}
+
+/// Writes `rows` below `header` with right-aligned columns.
+fn write_table<const N: usize>(
+    f: &mut Formatter<'_>,
+    header: [&str; N],
+    rows: impl Iterator<Item = [u64; N]>,
+) -> std::fmt::Result {
+    let rows = rows
+        .map(|row| row.map(|cell| cell.to_string()))
+        .collect::<Vec<_>>();
+    let widths = (0..N).map(|col| {
+        rows.iter()
+            .map(|row| row[col].len())
+            .chain([header[col].len()])
+            .max()
+            .unwrap_or(0)
+    });
+    let widths = widths.collect::<Vec<_>>();
+
+    let header = header.map(str::to_owned);
+    for row in std::iter::once(&header).chain(&rows) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:>width$}"))
+            .join("  ");
+        writeln!(f, "  {line}")?;
+    }
+    Ok(())
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values = |values: &[u64]| match values {
+            [] => "-".to_owned(),
+            values => values.iter().join(", "),
+        };
+
+        writeln!(f, "pairs after sorting:")?;
+        write_table(
+            f,
+            ["left", "right", "distance"],
+            self.pairs
+                .iter()
+                .map(|pair| [pair.left, pair.right, pair.distance]),
+        )?;
+        writeln!(f, "total distance: {}", self.total_distance())?;
+
+        writeln!(f, "\nlargest {} gaps:", self.largest_gaps.len())?;
+        write_table(
+            f,
+            ["index", "left", "right", "distance"],
+            self.largest_gaps.iter().map(|&idx| {
+                let pair = self.pairs[idx];
+                [idx as u64, pair.left, pair.right, pair.distance]
+            }),
+        )?;
+
+        writeln!(f, "\nonly in left list: {}", values(&self.only_left))?;
+        writeln!(f, "only in right list: {}", values(&self.only_right))?;
+
+        writeln!(f, "\nsimilarity by value:")?;
+        write_table(
+            f,
+            ["value", "left", "right", "contribution"],
+            self.similarity.iter().map(|entry| {
+                [
+                    entry.value,
+                    entry.left_count,
+                    entry.right_count,
+                    entry.contribution,
+                ]
+            }),
+        )?;
+        write!(f, "total similarity: {}", self.total_similarity())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day01.txt");
+
+    #[test]
+    fn example() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part_a(&input), Some(11));
+        assert_eq!(Day01::part_b(&input), Some(31));
+    }
+
+    #[test]
+    fn reports_pairs_and_values() {
+        let (a, b) = Day01::parse(EXAMPLE).unwrap();
+        let report = report(&a, &b, 2);
+        assert_eq!(
+            Some(report.total_distance()),
+            Day01::part_a(&(a.clone(), b.clone()))
+        );
+        assert_eq!(Some(report.total_similarity()), Day01::part_b(&(a, b)));
+
+        assert_eq!(
+            report.pairs[0],
+            Pair {
+                left: 1,
+                right: 3,
+                distance: 2
+            }
+        );
+        // Two pairs with a distance of 2, the earlier one wins the tie
+        assert_eq!(report.largest_gaps, [5, 0]);
+        assert_eq!(report.only_left, [1, 2]);
+        assert_eq!(report.only_right, [5, 9]);
+        assert_eq!(
+            report.similarity[2],
+            Similarity {
+                value: 3,
+                left_count: 3,
+                right_count: 3,
+                contribution: 27
+            }
+        );
+
+        let text = report.to_string();
+        assert!(text
+            .contains("  value  left  right  contribution\n      1     1      0             0\n"));
+        assert!(text.ends_with("total similarity: 31"));
+        assert!(report
+            .to_json()
+            .to_string()
+            .starts_with(r#"{"total_distance":11,"total_similarity":31,"pairs":[{"left":1,"#));
+    }
+
+    #[test]
+    fn external_lists_match_in_memory() {
+        let mut rng = crate::rng::Rng::new(1);
+        let input = (0..500)
+            .map(|_| format!("{}   {}\n", rng.below(50), rng.below(50)))
+            .collect::<String>();
+        let input = EXAMPLE.to_owned() + &input;
+
+        let (a, b) = Day01::parse(&input).unwrap();
+        let lines = input.lines().map(|line| Ok(line.to_owned()));
+        let external = ExternalLists::spill(lines, &std::env::temp_dir(), 16).unwrap();
+        assert_eq!(
+            external.total_distance().unwrap(),
+            calculate_total_diff(&a, &b)
+        );
+        assert_eq!(external.similarity().unwrap(), calculate_similarity(&a, &b));
+
+        let lines = ["3   4", "4"].map(|line| Ok(line.to_owned()));
+        let err = ExternalLists::spill(lines.into_iter(), &std::env::temp_dir(), 16);
+        assert!(matches!(err, Err(ExternalError::Parse(err)) if err.line == 2));
+    }
+}
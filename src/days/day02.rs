@@ -0,0 +1,551 @@
+use crate::days::DayReport;
+use crate::json::Json;
+use crate::settings::Settings;
+use crate::{parse_input_reports, ParseError, Part, Solution};
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input_reports(input.as_bytes())
+    }
+
+    fn part_a(reports: &Self::Input) -> Option<u64> {
+        Some(count_safe(reports, Part::A, &Options::default()))
+    }
+
+    fn part_b(reports: &Self::Input) -> Option<u64> {
+        Some(count_safe(reports, Part::B, &Options::default()))
+    }
+
+    fn solve_with(reports: &Self::Input, part: Part, settings: &Settings) -> Option<u64> {
+        let options =
+            Options::from_settings(settings).expect("settings are checked before solving");
+        Some(count_safe(reports, part, &options))
+    }
+}
+
+/// Part a counts the reports that are safe under the policy, part b the ones that are safe after
+/// removing at most `tolerance` levels.
+fn count_safe(reports: &[Vec<u64>], part: Part, options: &Options) -> u64 {
+    let Options { tolerance, policy } = options;
+    reports
+        .iter()
+        .filter(|report| match part {
+            Part::A => policy.is_safe(report),
+            Part::B => dampen(report, *tolerance, policy).is_some(),
+        })
+        .count() as u64
+}
+
+/// Rules of the day that may differ from the puzzle, the defaults are the rules of the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Number of levels the Problem Dampener may remove from a report
+    pub tolerance: usize,
+    /// When a report counts as safe, with or without the Problem Dampener
+    pub policy: SafetyPolicy,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            tolerance: 1,
+            policy: SafetyPolicy::default(),
+        }
+    }
+}
+
+impl Options {
+    /// Reads the options from the keys `tolerance`, `min_delta`, `max_delta`, `direction` and
+    /// `max_drift`. Keys that are left out keep their default, the resulting policy has to be
+    /// valid.
+    pub fn from_settings(settings: &Settings) -> Result<Self, String> {
+        let mut options = Options::default();
+        for (key, value) in settings.iter() {
+            match key {
+                "tolerance" => {
+                    options.tolerance = value
+                        .parse()
+                        .map_err(|e| format!("invalid tolerance '{value}': {e}"))?
+                }
+                _ => options.policy.set(key, value)?,
+            }
+        }
+        options.policy.validate()?;
+        Ok(options)
+    }
+}
+
+/// In which direction the levels of a safe report may change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// All levels strictly increasing or all strictly decreasing
+    Monotone,
+    /// All levels increasing or all decreasing, equal levels in between are allowed
+    NonStrict,
+    /// Any direction, step by step
+    Either,
+}
+
+impl Direction {
+    /// Predicates on the difference between two consecutive levels, a report is safe if all its
+    /// differences satisfy one of them.
+    fn orientations(self) -> &'static [fn(i64) -> bool] {
+        match self {
+            Direction::Monotone => &[|delta| delta > 0, |delta| delta < 0],
+            Direction::NonStrict => &[|delta| delta >= 0, |delta| delta <= 0],
+            Direction::Either => &[|_| true],
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "monotone" => Ok(Direction::Monotone),
+            "non-strict" => Ok(Direction::NonStrict),
+            "either" => Ok(Direction::Either),
+            _ => Err(format!(
+                "invalid direction '{s}', expected 'monotone', 'non-strict' or 'either'"
+            )),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Monotone => f.pad("monotone"),
+            Direction::NonStrict => f.pad("non-strict"),
+            Direction::Either => f.pad("either"),
+        }
+    }
+}
+
+/// When a report counts as safe. The default is the rule of the puzzle: levels change by 1 to 3
+/// per step, always in the same direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    pub min_delta: u64,
+    pub max_delta: u64,
+    pub direction: Direction,
+    /// Largest difference between the first and the last level
+    pub max_drift: Option<u64>,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        SafetyPolicy {
+            min_delta: 1,
+            max_delta: 3,
+            direction: Direction::Monotone,
+            max_drift: None,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    /// Sets the setting named `key`, one of `min_delta`, `max_delta`, `direction` and
+    /// `max_drift`.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let number = |value: &str| {
+            value
+                .parse::<u64>()
+                .map_err(|e| format!("invalid {key} '{value}': {e}"))
+        };
+        match key {
+            "min_delta" => self.min_delta = number(value)?,
+            "max_delta" => self.max_delta = number(value)?,
+            "direction" => self.direction = value.parse()?,
+            "max_drift" => self.max_drift = Some(number(value)?),
+            _ => return Err(format!("unknown setting '{key}'")),
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min_delta > self.max_delta {
+            return Err(format!(
+                "min_delta {} is larger than max_delta {}",
+                self.min_delta, self.max_delta
+            ));
+        }
+        Ok(())
+    }
+
+    fn is_step(&self, orientation: fn(i64) -> bool, a: u64, b: u64) -> bool {
+        orientation(b as i64 - a as i64)
+            && (self.min_delta..=self.max_delta).contains(&a.abs_diff(b))
+    }
+
+    fn within_drift(&self, first: u64, last: u64) -> bool {
+        self.max_drift
+            .is_none_or(|drift| first.abs_diff(last) <= drift)
+    }
+
+    pub fn is_safe(&self, levels: &[u64]) -> bool {
+        let (Some(&first), Some(&last)) = (levels.first(), levels.last()) else {
+            return true;
+        };
+
+        self.within_drift(first, last)
+            && self.direction.orientations().iter().any(|&orientation| {
+                levels
+                    .iter()
+                    .tuple_windows()
+                    .all(|(&a, &b)| self.is_step(orientation, a, b))
+            })
+    }
+
+    pub fn to_json(&self) -> Json {
+        Json::object([
+            ("min_delta", self.min_delta.into()),
+            ("max_delta", self.max_delta.into()),
+            ("direction", self.direction.to_string().into()),
+            ("max_drift", self.max_drift.map_or(Json::Null, Json::from)),
+        ])
+    }
+}
+
+/// The Problem Dampener: the levels to remove so that `report` becomes safe under `policy`, at
+/// most `tolerance` of them and as few as possible. `None` if more levels would have to be
+/// removed.
+///
+/// Runs in O(n * tolerance): between two levels that are kept there can't be more than
+/// `tolerance` removed ones, so only that many predecessors have to be considered per level. A
+/// maximum drift depends on the first level that is kept, which adds a factor of `tolerance`.
+pub fn dampen(report: &[u64], tolerance: usize, policy: &SafetyPolicy) -> Option<Vec<usize>> {
+    let len = report.len();
+    if len == 0 {
+        return Some(vec![]);
+    }
+    let tolerance = tolerance.min(len - 1);
+
+    policy
+        .direction
+        .orientations()
+        .iter()
+        .flat_map(|&orientation| {
+            let chain = move |first_levels| {
+                min_removals(
+                    report,
+                    tolerance,
+                    first_levels,
+                    |a, b| policy.is_step(orientation, a, b),
+                    |first, last| policy.within_drift(first, last),
+                )
+            };
+            match policy.max_drift {
+                None => vec![chain(0..=tolerance)],
+                Some(_) => (0..=tolerance).map(|first| chain(first..=first)).collect(),
+            }
+        })
+        .flatten()
+        .min_by_key(Vec::len)
+}
+
+/// Fewest removals, if at most `tolerance`, so that every pair of consecutive remaining levels
+/// is a valid `step`. The first level kept is one of `first_levels`, which must be ordered, and
+/// the first and last level that are kept are checked with `ends`.
+fn min_removals(
+    report: &[u64],
+    tolerance: usize,
+    first_levels: RangeInclusive<usize>,
+    step: impl Fn(u64, u64) -> bool,
+    ends: impl Fn(u64, u64) -> bool,
+) -> Option<Vec<usize>> {
+    let len = report.len();
+    // Fewest levels removed up to level i if it is kept, the kept level before it and the first
+    // kept level
+    let mut removed = vec![None::<usize>; len];
+    let mut previous = vec![None::<usize>; len];
+    let mut first = vec![0; len];
+
+    for idx in *first_levels.start()..len {
+        if first_levels.contains(&idx) {
+            removed[idx] = Some(idx);
+            first[idx] = idx;
+        }
+
+        for prev_idx in idx.saturating_sub(tolerance + 1)..idx {
+            let Some(prev_removed) = removed[prev_idx] else {
+                continue;
+            };
+            let total = prev_removed + (idx - prev_idx - 1);
+            if total <= tolerance
+                && removed[idx].is_none_or(|best| total < best)
+                && step(report[prev_idx], report[idx])
+            {
+                removed[idx] = Some(total);
+                previous[idx] = Some(prev_idx);
+                first[idx] = first[prev_idx];
+            }
+        }
+    }
+
+    let (last, _) = (0..len)
+        .filter_map(|idx| Some((idx, removed[idx]? + (len - 1 - idx))))
+        .filter(|&(idx, total)| total <= tolerance && ends(report[first[idx]], report[idx]))
+        .min_by_key(|&(_, total)| total)?;
+
+    let mut kept = vec![false; len];
+    let mut current = Some(last);
+    while let Some(idx) = current {
+        kept[idx] = true;
+        current = previous[idx];
+    }
+    Some((0..len).filter(|&idx| !kept[idx]).collect())
+}
+
+/// Which reports are safe with the Problem Dampener and what it removed from them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub tolerance: usize,
+    pub policy: SafetyPolicy,
+    /// Every report with the indices of the levels to remove, `None` if it is unsafe
+    pub checked: Vec<(Vec<u64>, Option<Vec<usize>>)>,
+}
+
+/// Runs the Problem Dampener with the given options on every report.
+pub fn report(reports: &[Vec<u64>], options: &Options) -> Report {
+    let Options { tolerance, policy } = *options;
+    Report {
+        tolerance,
+        policy,
+        checked: reports
+            .iter()
+            .map(|levels| (levels.clone(), dampen(levels, tolerance, &policy)))
+            .collect(),
+    }
+}
+
+impl Report {
+    /// Reports that are safe without removing levels
+    pub fn safe(&self) -> usize {
+        self.checked
+            .iter()
+            .filter(|(_, dropped)| dropped.as_ref().is_some_and(Vec::is_empty))
+            .count()
+    }
+
+    /// Reports that are only safe after removing levels
+    pub fn dampened(&self) -> usize {
+        self.checked
+            .iter()
+            .filter(|(_, dropped)| dropped.as_ref().is_some_and(|dropped| !dropped.is_empty()))
+            .count()
+    }
+
+    pub fn unsafe_reports(&self) -> usize {
+        self.checked
+            .iter()
+            .filter(|(_, dropped)| dropped.is_none())
+            .count()
+    }
+}
+
+impl DayReport for Report {
+    fn to_json(&self) -> Json {
+        Json::object([
+            ("tolerance", self.tolerance.into()),
+            ("policy", self.policy.to_json()),
+            ("safe", self.safe().into()),
+            ("dampened", self.dampened().into()),
+            ("unsafe", self.unsafe_reports().into()),
+            (
+                "reports",
+                Json::array(self.checked.iter().map(|(levels, dropped)| {
+                    let dropped = match dropped {
+                        Some(dropped) => Json::array(dropped.iter().map(|&idx| {
+                            Json::object([("index", idx.into()), ("value", levels[idx].into())])
+                        })),
+                        None => Json::Null,
+                    };
+                    Json::object([
+                        (
+                            "levels",
+                            Json::array(levels.iter().map(|&level| level.into())),
+                        ),
+                        ("dropped", dropped),
+                    ])
+                })),
+            ),
+        ])
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let levels_width = self
+            .checked
+            .iter()
+            .map(|(levels, _)| levels.iter().join(" ").len())
+            .max()
+            .unwrap_or(0);
+        for (idx, (levels, dropped)) in self.checked.iter().enumerate() {
+            let status = match dropped.as_deref() {
+                None => "unsafe".to_owned(),
+                Some([]) => "safe".to_owned(),
+                Some(dropped) => format!(
+                    "safe after dropping {}",
+                    dropped
+                        .iter()
+                        .map(|&idx| format!("level {} ({})", idx + 1, levels[idx]))
+                        .join(", ")
+                ),
+            };
+            let levels = levels.iter().join(" ");
+            writeln!(f, "{:>5}  {levels:<levels_width$}  {status}", idx + 1)?;
+        }
+
+        write!(
+            f,
+            "{} safe, {} safe after dropping up to {} levels, {} unsafe",
+            self.safe(),
+            self.dampened(),
+            self.tolerance,
+            self.unsafe_reports()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day02.txt");
+
+    #[test]
+    fn example() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part_a(&input), Some(2));
+        assert_eq!(Day02::part_b(&input), Some(4));
+
+        let mut settings = Settings::default();
+        settings.set("tolerance", "2");
+        assert_eq!(Day02::solve_with(&input, Part::B, &settings), Some(6));
+    }
+
+    /// Fewest removals found by trying every subset of levels
+    fn brute_force_removals(report: &[u64], policy: &SafetyPolicy) -> usize {
+        (0..=report.len())
+            .find(|&count| {
+                (0..report.len()).combinations(count).any(|removed| {
+                    let levels = (0..report.len())
+                        .filter(|idx| !removed.contains(idx))
+                        .map(|idx| report[idx])
+                        .collect::<Vec<_>>();
+                    policy.is_safe(&levels)
+                })
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn dampens_minimally() {
+        let policy = SafetyPolicy::default();
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(dampen(&input[0], 0, &policy), Some(vec![]));
+        assert_eq!(dampen(&input[1], 1, &policy), None);
+        assert_eq!(dampen(&input[1], 2, &policy), Some(vec![0, 1]));
+        // Dropping either the 3 or the 2 works
+        assert_eq!(dampen(&input[3], 1, &policy), Some(vec![2]));
+        assert_eq!(dampen(&[], 0, &policy), Some(vec![]));
+
+        let mut rng = crate::rng::Rng::new(2);
+        let directions = [Direction::Monotone, Direction::NonStrict, Direction::Either];
+        for _ in 0..300 {
+            let min_delta = rng.below(3);
+            let policy = SafetyPolicy {
+                min_delta,
+                max_delta: min_delta + rng.below(4),
+                direction: *rng.choose(&directions),
+                max_drift: rng.chance(50).then(|| rng.below(8)),
+            };
+            let report = (0..rng.range(1..=8))
+                .map(|_| rng.below(10))
+                .collect::<Vec<_>>();
+            let fewest = brute_force_removals(&report, &policy);
+            for tolerance in 0..4 {
+                let dropped = dampen(&report, tolerance, &policy);
+                assert_eq!(
+                    dropped.as_ref().map(Vec::len),
+                    (fewest <= tolerance).then_some(fewest),
+                    "{report:?} with {policy:?}"
+                );
+                if let Some(dropped) = dropped {
+                    let levels = (0..report.len())
+                        .filter(|idx| !dropped.contains(idx))
+                        .map(|idx| report[idx])
+                        .collect::<Vec<_>>();
+                    assert!(policy.is_safe(&levels), "{report:?} without {dropped:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn applies_policy() {
+        let settings =
+            Settings::from_config("min_delta = 0\nmax_delta = 5\ndirection = non-strict").unwrap();
+        let policy = Options::from_settings(&settings).unwrap().policy;
+        assert_eq!(
+            policy,
+            SafetyPolicy {
+                min_delta: 0,
+                max_delta: 5,
+                direction: Direction::NonStrict,
+                max_drift: None
+            }
+        );
+        assert!(policy.is_safe(&[1, 1, 6, 6]));
+        assert!(!policy.is_safe(&[1, 1, 7]));
+        assert!(!policy.is_safe(&[1, 2, 1]));
+
+        let drifting = SafetyPolicy {
+            direction: Direction::Either,
+            max_drift: Some(2),
+            ..SafetyPolicy::default()
+        };
+        assert!(drifting.is_safe(&[5, 8, 6, 7]));
+        assert!(!drifting.is_safe(&[5, 8, 6, 8]));
+        assert_eq!(dampen(&[5, 8, 6, 8], 1, &drifting), Some(vec![3]));
+
+        let options =
+            |config: &str| Options::from_settings(&Settings::from_config(config).unwrap());
+        assert_eq!(
+            options("max_delta = 3\nspeed = 1\n"),
+            Err("unknown setting 'speed'".to_owned())
+        );
+        assert!(options("direction = up").is_err());
+        assert!(options("tolerance = -1").is_err());
+        assert!(options("min_delta = 4").is_err());
+        let policy = options("min_delta = 4\nmax_delta = 9").unwrap().policy;
+        assert_eq!((policy.min_delta, policy.max_delta), (4, 9));
+    }
+
+    #[test]
+    fn reports_dropped_levels() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        let report = report(&input, &Options::default());
+        assert_eq!((report.safe(), report.dampened()), (2, 2));
+        assert!(report
+            .to_string()
+            .contains("    4  1 3 2 4 5  safe after dropping level 3 (2)\n"));
+        assert!(report
+            .to_json()
+            .to_string()
+            .contains(r#"{"levels":[1,3,2,4,5],"dropped":[{"index":2,"value":2}]}"#));
+    }
+}
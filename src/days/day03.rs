@@ -0,0 +1,91 @@
+use crate::{ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part_a(lines: &Self::Input) -> Option<u64> {
+        Some(
+            lines
+                .iter()
+                .flat_map(|line| parse_mul_statements(line).into_iter().map(|(a, b)| a * b))
+                .sum(),
+        )
+    }
+
+    fn part_b(lines: &Self::Input) -> Option<u64> {
+        Some(
+            lines
+                .iter()
+                .flat_map(|line| parse_instructions(line))
+                .fold((true, 0), |(active, sum), instruction| match instruction {
+                    Instruction::Mul(a, b) if active => (active, sum + (a * b)),
+                    Instruction::Do => (true, sum),
+                    Instruction::Dont => (false, sum),
+                    _ => (active, sum),
+                })
+                .1,
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+fn parse_mul_statements(line: &str) -> Vec<(u64, u64)> {
+    let re = regex::Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+
+    re.captures_iter(line)
+        .map(|cap| {
+            let a = cap[1].parse().expect("not a number");
+            let b = cap[2].parse().expect("not a number");
+            (a, b)
+        })
+        .collect()
+}
+
+fn parse_instructions(line: &str) -> Vec<Instruction> {
+    let re = regex::Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)").unwrap();
+
+    re.captures_iter(line)
+        .map(|cap| match &cap[0][0..3] {
+            "do(" => Instruction::Do,
+            "don" => Instruction::Dont,
+            "mul" => {
+                let a = cap[1].parse().expect("not a number");
+                let b = cap[2].parse().expect("not a number");
+                Instruction::Mul(a, b)
+            }
+            _ => panic!("Invalid match"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_A: &str = include_str!("../../inputs/examples/day03a.txt");
+
+    const EXAMPLE_B: &str = include_str!("../../inputs/examples/day03b.txt");
+
+    #[test]
+    fn example() {
+        let input = Day03::parse(EXAMPLE_A).unwrap();
+        assert_eq!(Day03::part_a(&input), Some(161));
+
+        let input = Day03::parse(EXAMPLE_B).unwrap();
+        assert_eq!(Day03::part_b(&input), Some(48));
+    }
+}
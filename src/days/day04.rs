@@ -0,0 +1,79 @@
+use crate::{Dir8, Grid, ParseError, Solution, Vec2};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(input)
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<u64> {
+        Some(find_xmas_times(grid) as u64)
+    }
+
+    fn part_b(grid: &Self::Input) -> Option<u64> {
+        Some(find_x_mas_times(grid) as u64)
+    }
+}
+
+fn find_xmas_times(grid: &Grid<char>) -> usize {
+    grid.positions_of(&'X')
+        .map(|x_coordinate| {
+            Dir8::ALL
+                .iter()
+                .filter(|direction| check_xmas_from_coordinate(grid, x_coordinate, **direction))
+                .count()
+        })
+        .sum()
+}
+
+fn find_x_mas_times(grid: &Grid<char>) -> usize {
+    grid.positions_of(&'A')
+        .filter(|a_coordinate| check_x_mas_from_coordinate(grid, *a_coordinate))
+        .count()
+}
+
+fn check_xmas_from_coordinate(grid: &Grid<char>, coordinates: Vec2, direction: Dir8) -> bool {
+    const XMAS: &str = "XMAS";
+
+    grid.ray(coordinates, direction.to_vec2())
+        .map(|(_, c)| *c)
+        .take(XMAS.len())
+        .eq(XMAS.chars())
+}
+
+fn check_x_mas_from_coordinate(grid: &Grid<char>, coordinates: Vec2) -> bool {
+    const DIAGONALS: [Dir8; 2] = [Dir8::SouthEast, Dir8::NorthEast];
+    const MATCH: [char; 2] = ['M', 'S'];
+
+    let check_match = |directions: [Dir8; 2], match_iter: Box<dyn Iterator<Item = char>>| {
+        directions
+            .iter()
+            .zip(match_iter)
+            .all(|(direction, c)| grid.get(coordinates + direction.to_vec2()) == Some(&c))
+    };
+
+    DIAGONALS.iter().all(|&diagonal| {
+        let directions = [diagonal, diagonal.reverse()];
+        check_match(directions, Box::new(MATCH.iter().copied()))
+            || check_match(directions, Box::new(MATCH.iter().rev().copied()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day04.txt");
+
+    #[test]
+    fn example() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part_a(&input), Some(18));
+        assert_eq!(Day04::part_b(&input), Some(9));
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{InputLine, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<(u64, u64)>, Vec<Vec<u64>>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a((rules, orderings): &Self::Input) -> Option<u64> {
+        let precedence_map = precedence_map(rules);
+
+        Some(
+            orderings
+                .iter()
+                .filter_map(|ordering| {
+                    if is_ordering_valid(ordering, &precedence_map) {
+                        Some(ordering[ordering.len() / 2])
+                    } else {
+                        None
+                    }
+                })
+                .sum(),
+        )
+    }
+
+    fn part_b((rules, orderings): &Self::Input) -> Option<u64> {
+        let precedence_map = precedence_map(rules);
+
+        Some(
+            orderings
+                .iter()
+                .filter_map(|ordering| {
+                    if is_ordering_valid(ordering, &precedence_map) {
+                        return None;
+                    }
+
+                    let ordered = topo_sort_stable(ordering.clone(), &precedence_map);
+                    Some(ordered[ordered.len() / 2])
+                })
+                .sum(),
+        )
+    }
+}
+
+type Rules = Vec<(u64, u64)>;
+type Orderings = Vec<Vec<u64>>;
+
+fn parse_input(input: &str) -> Result<(Rules, Orderings), ParseError> {
+    let mut lines_iter = InputLine::enumerate(input);
+
+    let rules = (&mut lines_iter)
+        .take_while(|line| !line.text.is_empty())
+        .map(|line| {
+            let mut parts = line.text.split('|');
+            let a = line.parse_number(line.expect_token(parts.next(), "page before '|'")?)?;
+            let b = line.parse_number(line.expect_token(parts.next(), "page after '|'")?)?;
+            Ok((a, b))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    let orderings = lines_iter
+        .map(|line| {
+            line.text
+                .split(',')
+                .map(|num_str| line.parse_number(num_str))
+                .collect()
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    Ok((rules, orderings))
+}
+
+fn precedence_map(rules: &[(u64, u64)]) -> HashMap<u64, HashSet<u64>> {
+    let mut precedence_map = HashMap::<u64, HashSet<u64>>::new();
+    for &(a, b) in rules {
+        precedence_map.entry(b).or_default().insert(a);
+    }
+    precedence_map
+}
+
+fn is_ordering_valid(ordering: &[u64], precedence_map: &HashMap<u64, HashSet<u64>>) -> bool {
+    for (idx, num) in ordering.iter().copied().enumerate() {
+        for later_num in &ordering[idx + 1..] {
+            if precedence_map
+                .get(&num)
+                .is_some_and(|nums_before_set| nums_before_set.contains(later_num))
+            {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn topo_sort_stable(values: Vec<u64>, precedence_map: &HashMap<u64, HashSet<u64>>) -> Vec<u64> {
+    let mut remaining = values;
+    let mut ordered = Vec::new();
+
+    while let Some(value) = remaining.first().copied() {
+        let sorted_precedence_values = precedence_map
+            .get(&value)
+            .map(|precedence_set| {
+                let values_with_precedence = remaining[1..]
+                    .iter()
+                    .copied()
+                    .filter(|value| precedence_set.contains(value))
+                    .collect::<Vec<_>>();
+                topo_sort_stable(values_with_precedence, precedence_map)
+            })
+            .unwrap_or_default();
+
+        remaining.retain(|v| !sorted_precedence_values.contains(v) && *v != value);
+
+        ordered.extend(sorted_precedence_values);
+        ordered.push(value);
+    }
+
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day05.txt");
+
+    #[test]
+    fn example() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part_a(&input), Some(143));
+        assert_eq!(Day05::part_b(&input), Some(123));
+    }
+}
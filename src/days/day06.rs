@@ -0,0 +1,263 @@
+use crate::metrics;
+use crate::simulation::{Driver, Run, Simulation};
+use crate::{Dir4, Grid, ParseError, Solution, Vec2};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Lab;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid::parse_with(input, "one of '.', '#' or '^'", |_, c| {
+            matches!(c, '.' | '#' | '^').then_some(c)
+        })?;
+        let guard_position = grid
+            .find(|&c| c == '^')
+            .ok_or_else(|| ParseError::missing_element(grid.height(), "guard"))?;
+
+        Ok(Lab {
+            field_size: grid.size(),
+            guard_position,
+            obstacles: grid.positions_of(&'#').collect(),
+        })
+    }
+
+    fn part_a(lab: &Self::Input) -> Option<u64> {
+        let trace = generate_trace(
+            lab.field_size,
+            lab.obstacles.clone(),
+            lab.guard_position,
+            Dir4::North,
+        );
+        Some(trace.iter().map(|(_, coords)| coords).unique().count() as u64)
+    }
+
+    fn part_b(lab: &Self::Input) -> Option<u64> {
+        let trace = generate_trace(
+            lab.field_size,
+            lab.obstacles.clone(),
+            lab.guard_position,
+            Dir4::North,
+        );
+        Some(possible_diversion_points(lab.field_size, trace, &lab.obstacles).len() as u64)
+    }
+}
+
+pub struct Lab {
+    field_size: Vec2,
+    guard_position: Vec2,
+    obstacles: HashSet<Vec2>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub position: Vec2,
+    pub direction: Dir4,
+}
+
+/// The guard's patrol as a simulation, every step is either a move or a turn. It is done once the
+/// guard left the lab or is stuck in a loop.
+pub struct Patrol {
+    field_size: Vec2,
+    obstacles: HashSet<Vec2>,
+    guard: Guard,
+    visited: HashSet<Guard>,
+    looping: bool,
+}
+
+impl Patrol {
+    pub fn new(lab: &Lab) -> Self {
+        let guard = Guard {
+            position: lab.guard_position,
+            direction: Dir4::North,
+        };
+        Patrol {
+            field_size: lab.field_size,
+            obstacles: lab.obstacles.clone(),
+            guard,
+            visited: HashSet::from([guard]),
+            looping: false,
+        }
+    }
+
+    pub fn is_looping(&self) -> bool {
+        self.looping
+    }
+
+    pub fn visited_positions(&self) -> usize {
+        self.visited
+            .iter()
+            .map(|guard| guard.position)
+            .unique()
+            .count()
+    }
+}
+
+impl Simulation for Patrol {
+    type State = Guard;
+
+    fn state(&self) -> &Guard {
+        &self.guard
+    }
+
+    fn step(&mut self) {
+        let next_pos = self.guard.position + self.guard.direction.to_vec2();
+        if self.obstacles.contains(&next_pos) {
+            self.guard.direction = self.guard.direction.turn_right();
+        } else {
+            self.guard.position = next_pos;
+        }
+
+        if self.guard.position.is_within_bounds(self.field_size) {
+            self.looping = !self.visited.insert(self.guard);
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.looping || !self.guard.position.is_within_bounds(self.field_size)
+    }
+
+    fn render(&self) -> Grid<char> {
+        let mut grid = Grid::new(self.field_size.x as usize, self.field_size.y as usize, '.');
+        for &obstacle in &self.obstacles {
+            grid[obstacle] = '#';
+        }
+        for guard in &self.visited {
+            grid[guard.position] = 'X';
+        }
+        if let Some(cell) = grid.get_mut(self.guard.position) {
+            *cell = self.guard.direction.arrow();
+        }
+        grid
+    }
+
+    fn status(&self) -> String {
+        let Guard {
+            position,
+            direction,
+        } = self.guard;
+        let state = if self.looping {
+            ", stuck in a loop"
+        } else if !position.is_within_bounds(self.field_size) {
+            ", left the lab"
+        } else {
+            ""
+        };
+        format!(
+            "guard at {position} facing {}, {} positions visited{state}",
+            direction.arrow(),
+            self.visited_positions()
+        )
+    }
+}
+
+/// Runs the guard's patrol through `lab` until it leaves.
+pub fn simulate(lab: &Lab, driver: &Driver) -> Run {
+    driver.run(&mut Patrol::new(lab))
+}
+
+fn trace_iter(
+    obstacles: HashSet<Vec2>,
+    start_position: Vec2,
+    start_direction: Dir4,
+) -> impl Iterator<Item = (Dir4, Vec2)> {
+    let mut current_pos = start_position;
+    let mut direction = start_direction;
+
+    let steps = std::iter::repeat(()).filter_map(move |()| {
+        let next_pos = current_pos + direction.to_vec2();
+
+        if obstacles.contains(&next_pos) {
+            direction = direction.turn_right();
+            return None;
+        }
+        current_pos = next_pos;
+
+        Some((direction, next_pos))
+    });
+
+    std::iter::once((start_direction, start_position)).chain(steps)
+}
+
+fn generate_trace(
+    field_size: Vec2,
+    obstacles: HashSet<Vec2>,
+    start_position: Vec2,
+    start_direction: Dir4,
+) -> Vec<(Dir4, Vec2)> {
+    trace_iter(obstacles, start_position, start_direction)
+        .take_while(|(_, coords)| coords.is_within_bounds(field_size))
+        .collect()
+}
+
+fn possible_diversion_points(
+    field_size: Vec2,
+    trace: Vec<(Dir4, Vec2)>,
+    obstacles: &HashSet<Vec2>,
+) -> Vec<Vec2> {
+    let Some((start_direction, start_position)) = trace.first().copied() else {
+        return vec![];
+    };
+
+    trace
+        .into_iter()
+        .skip(1)
+        .map(|(_, coords)| coords)
+        .unique()
+        .filter(|&potential_obstacle| {
+            let mut obstacles = obstacles.clone();
+            assert!(obstacles.insert(potential_obstacle));
+            metrics::count("obstacles_tried", 1);
+
+            // The guard is stuck in a loop if it visits the same field in the same direction twice
+            let looping = !trace_iter(obstacles, start_position, start_direction)
+                .take_while(|(_, coords)| coords.is_within_bounds(field_size))
+                .all_unique();
+            if looping {
+                metrics::count("loops_detected", 1);
+            }
+            looping
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::Stop;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day06.txt");
+
+    #[test]
+    fn example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part_a(&input), Some(41));
+        assert_eq!(Day06::part_b(&input), Some(6));
+    }
+
+    #[test]
+    fn simulated_patrol() {
+        let mut lab = Day06::parse(EXAMPLE).unwrap();
+        let mut patrol = Patrol::new(&lab);
+        let run = Driver::new().run(&mut patrol);
+        assert_eq!(run.stop, Stop::Done);
+        assert!(!patrol.is_looping());
+        assert_eq!(patrol.visited_positions(), 41);
+
+        let mut patrol = Patrol::new(&lab);
+        Driver::new().step_limit(3).run(&mut patrol);
+        assert_eq!(
+            patrol.render().row(3).iter().collect::<String>(),
+            "..#.^....."
+        );
+
+        lab.obstacles.insert(Vec2::new(3, 6));
+        let mut patrol = Patrol::new(&lab);
+        Driver::new().run(&mut patrol);
+        assert!(patrol.is_looping());
+    }
+}
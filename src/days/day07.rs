@@ -0,0 +1,106 @@
+use crate::metrics;
+use crate::{InputLine, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(expressions: &Self::Input) -> Option<u64> {
+        Some(sum_valid_results(
+            expressions,
+            &[Operator::Add, Operator::Multiply],
+        ))
+    }
+
+    fn part_b(expressions: &Self::Input) -> Option<u64> {
+        Some(sum_valid_results(
+            expressions,
+            &[Operator::Add, Operator::Multiply, Operator::Concatenate],
+        ))
+    }
+}
+
+fn parse_line(line: InputLine) -> Result<(u64, Vec<u64>), ParseError> {
+    let mut parts = line.text.split(": ");
+    let result = line.parse_number(line.expect_token(parts.next(), "test value")?)?;
+    let operands = line
+        .expect_token(parts.next(), "operands after ': '")?
+        .split(' ')
+        .map(|x| line.parse_number(x))
+        .collect::<Result<_, _>>()?;
+    Ok((result, operands))
+}
+
+fn parse_input(input: &str) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
+    InputLine::enumerate(input).map(parse_line).collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Add,
+    Multiply,
+    Concatenate,
+}
+
+fn evaluate_term(operands: &[u64], operators: &[Operator]) -> u64 {
+    assert_eq!(operands.len(), operators.len() + 1);
+
+    operands[1..]
+        .iter()
+        .zip(operators)
+        .fold(operands[0], |acc, (operand, operator)| match operator {
+            Operator::Add => acc + operand,
+            Operator::Multiply => acc * operand,
+            Operator::Concatenate => format!("{}{}", acc, operand).parse().unwrap(),
+        })
+}
+
+fn find_valid_operator_combination(
+    operands: &[u64],
+    target: u64,
+    allowed_operators: &[Operator],
+) -> Option<Vec<Operator>> {
+    let num_operators = operands.len() - 1;
+    let mut operators_combinations = (0..num_operators)
+        .map(|_| allowed_operators.iter().copied())
+        .multi_cartesian_product();
+
+    let mut tried = 0;
+    let valid = operators_combinations.find(|operators| {
+        tried += 1;
+        evaluate_term(operands, operators.as_ref()) == target
+    });
+    metrics::count("combinations_tried", tried);
+    valid
+}
+
+fn sum_valid_results(expressions: &[(u64, Vec<u64>)], allowed_operators: &[Operator]) -> u64 {
+    expressions
+        .iter()
+        .filter_map(|(result, operands)| {
+            find_valid_operator_combination(operands, *result, allowed_operators).map(|_| *result)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day07.txt");
+
+    #[test]
+    fn example() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part_a(&input), Some(3749));
+        assert_eq!(Day07::part_b(&input), Some(11387));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{Grid, ParseError, Solution, Vec2};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(input)
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<u64> {
+        Some(count_antinodes(grid, get_antinodes))
+    }
+
+    fn part_b(grid: &Self::Input) -> Option<u64> {
+        Some(count_antinodes(grid, get_resonant_antinodes))
+    }
+}
+
+fn get_antennas(grid: &Grid<char>) -> HashMap<char, Vec<Vec2>> {
+    grid.iter()
+        .filter(|(_, c)| c.is_ascii_alphanumeric())
+        .map(|(pos, &c)| (c, pos))
+        .into_group_map()
+}
+
+fn get_antinodes(field_size: Vec2, antennas: &[Vec2]) -> HashSet<Vec2> {
+    antennas
+        .iter()
+        .copied()
+        .tuple_combinations()
+        .flat_map(|(a, b)| {
+            let delta = a - b;
+            let antinode_1 = a + delta;
+            let antinode_2 = b - delta;
+            [antinode_1, antinode_2]
+        })
+        .filter(|antinode| antinode.is_within_bounds(field_size))
+        .collect()
+}
+
+fn get_resonant_antinodes(field_size: Vec2, antennas: &[Vec2]) -> HashSet<Vec2> {
+    antennas
+        .iter()
+        .copied()
+        .tuple_combinations()
+        .flat_map(|(a, b)| {
+            let delta = a - b;
+            let antinodes_1 = (0..)
+                .map(move |dist| a + delta * dist)
+                .take_while(move |antinode| antinode.is_within_bounds(field_size));
+            let antinodes_2 = (0..)
+                .map(move |dist| b - delta * dist)
+                .take_while(move |antinode| antinode.is_within_bounds(field_size));
+
+            antinodes_1.chain(antinodes_2)
+        })
+        .collect()
+}
+
+fn count_antinodes(grid: &Grid<char>, antinodes_fn: fn(Vec2, &[Vec2]) -> HashSet<Vec2>) -> u64 {
+    let field_size = grid.size();
+    let antennas = get_antennas(grid);
+
+    let antinodes = antennas
+        .values()
+        .flat_map(|antennas| antinodes_fn(field_size, antennas))
+        .collect::<HashSet<_>>();
+
+    antinodes.len() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day08.txt");
+
+    #[test]
+    fn example() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part_a(&input), Some(14));
+        assert_eq!(Day08::part_b(&input), Some(34));
+    }
+}
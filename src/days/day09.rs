@@ -0,0 +1,264 @@
+use crate::differential::{shrink_number, Differential};
+use crate::{InputLine, ParseError, ParseErrorKind, Solution};
+use itertools::Itertools;
+use std::cmp::min;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<BlockRange>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_disk_map(input)
+    }
+
+    fn part_a(block_ranges: &Self::Input) -> Option<u64> {
+        let files = defragment_blocks(block_ranges);
+        Some(calculate_checksum(&files))
+    }
+
+    fn part_b(block_ranges: &Self::Input) -> Option<u64> {
+        let block_ranges = defragment_files(block_ranges.clone());
+        Some(calculate_block_range_checksum(&block_ranges))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum BlockRange {
+    File(File),
+    Free { len: u64 },
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct File {
+    index: u64,
+    len: u64,
+}
+
+fn parse_disk_map(input: &str) -> Result<Vec<BlockRange>, ParseError> {
+    let line = InputLine::enumerate(input)
+        .next()
+        .ok_or_else(|| ParseError::missing_element(0, "disk map"))?;
+    line.text
+        .chars()
+        .enumerate()
+        .map(|(idx, c)| {
+            let len = c.to_digit(10).ok_or_else(|| {
+                line.char_error(idx, c, ParseErrorKind::UnexpectedToken("a length digit"))
+            })? as u64;
+
+            Ok(if idx % 2 == 0 {
+                BlockRange::File(File {
+                    index: (idx / 2) as u64,
+                    len,
+                })
+            } else {
+                BlockRange::Free { len }
+            })
+        })
+        .collect()
+}
+
+/// Moves individual blocks from the end of the disk into the free space at the start, splitting
+/// files into fragments where necessary.
+fn defragment_blocks(block_ranges: &[BlockRange]) -> Vec<File> {
+    let files = block_ranges
+        .iter()
+        .enumerate()
+        .filter_map(|(block_idx, block)| match block {
+            BlockRange::File(f) => Some((block_idx, *f)),
+            BlockRange::Free { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut defragmented = vec![];
+    let mut files_rev = files.into_iter().rev();
+    let mut current_file: Option<(usize, File)> = None;
+    // Block index of the last file that was taken from the end, it and everything after it is
+    // moved already
+    let mut moved_from = block_ranges.len();
+    'outer: for (block_idx, block) in block_ranges.iter().enumerate() {
+        if moved_from <= block_idx {
+            break;
+        }
+
+        let mut free_space = match block {
+            BlockRange::File(f) => {
+                defragmented.push(*f);
+                continue;
+            }
+            BlockRange::Free { len } => *len,
+        };
+
+        while free_space > 0 {
+            let (_, file) = match &mut current_file {
+                Some(file) => file,
+                None => match files_rev.next() {
+                    Some((file_block_idx, file)) if file_block_idx > block_idx => {
+                        moved_from = file_block_idx;
+                        current_file.insert((file_block_idx, file))
+                    }
+                    // All remaining files come before this free range and stay where they are
+                    _ => break 'outer,
+                },
+            };
+
+            let fragment_len = min(free_space, file.len);
+            free_space -= fragment_len;
+            file.len -= fragment_len;
+
+            defragmented.push(File {
+                index: file.index,
+                len: fragment_len,
+            });
+
+            if file.len == 0 {
+                current_file = None;
+            }
+        }
+    }
+
+    if let Some((_, file)) = current_file {
+        defragmented.push(file);
+    }
+
+    defragmented
+}
+
+/// Moves whole files from the end of the disk into the first free range large enough to hold
+/// them.
+fn defragment_files(block_ranges: Vec<BlockRange>) -> Vec<BlockRange> {
+    let mut defragmented = block_ranges;
+    let mut rev_cursor = defragmented.len();
+    while rev_cursor > 0 {
+        rev_cursor -= 1;
+        let file = match defragmented[rev_cursor] {
+            BlockRange::File(f) => f,
+            BlockRange::Free { .. } => {
+                continue;
+            }
+        };
+
+        let Some((free_pos, free_range_len)) = defragmented[..rev_cursor]
+            .iter()
+            .find_position(
+                |block_range| matches!(block_range, BlockRange::Free { len } if *len >= file.len),
+            )
+            .map(|(free_pos, free_range)| {
+                let free_range_len = match free_range {
+                    BlockRange::File(_) => unreachable!(),
+                    BlockRange::Free { len } => *len,
+                };
+                (free_pos, free_range_len)
+            })
+        else {
+            continue;
+        };
+
+        defragmented[free_pos] = BlockRange::File(file);
+        defragmented[rev_cursor] = BlockRange::Free { len: file.len };
+
+        let new_free_range_len = free_range_len - file.len;
+        if new_free_range_len > 0 {
+            defragmented.insert(
+                free_pos + 1,
+                BlockRange::Free {
+                    len: new_free_range_len,
+                },
+            );
+            rev_cursor += 1;
+        }
+    }
+
+    defragmented
+}
+
+/// On disks where every file is a single block, moving blocks and moving whole files is the same
+/// thing, so both have to produce the same checksum. The input is the free space after each file
+/// but the last.
+pub fn differential() -> Differential<Vec<u64>, u64> {
+    Differential {
+        generate: |rng| (0..rng.index(30)).map(|_| rng.below(10)).collect(),
+        shrink: |gaps| {
+            let mut smaller = (0..gaps.len())
+                .map(|idx| [&gaps[..idx], &gaps[idx + 1..]].concat())
+                .collect::<Vec<_>>();
+            for (idx, &gap) in gaps.iter().enumerate() {
+                for smaller_gap in shrink_number(gap as i64, 0) {
+                    let mut gaps = gaps.clone();
+                    gaps[idx] = smaller_gap as u64;
+                    smaller.push(gaps);
+                }
+            }
+            smaller
+        },
+        describe: |gaps| gaps.iter().map(|gap| format!("1{gap}")).collect::<String>() + "1",
+        reference: |gaps| calculate_checksum(&defragment_blocks(&single_block_disk(gaps))),
+        optimized: |gaps| {
+            calculate_block_range_checksum(&defragment_files(single_block_disk(gaps)))
+        },
+    }
+}
+
+fn single_block_disk(gaps: &[u64]) -> Vec<BlockRange> {
+    let mut block_ranges = vec![BlockRange::File(File { index: 0, len: 1 })];
+    for (idx, &len) in gaps.iter().enumerate() {
+        block_ranges.push(BlockRange::Free { len });
+        block_ranges.push(BlockRange::File(File {
+            index: idx as u64 + 1,
+            len: 1,
+        }));
+    }
+    block_ranges
+}
+
+fn calculate_checksum(files: &[File]) -> u64 {
+    files
+        .iter()
+        .flat_map(|file| (0..file.len).map(|_| file.index))
+        .enumerate()
+        .map(|(mem_idx, file_idx)| (mem_idx as u64) * file_idx)
+        .sum()
+}
+
+fn calculate_block_range_checksum(block_ranges: &[BlockRange]) -> u64 {
+    block_ranges
+        .iter()
+        .flat_map(|block_range| {
+            let (len, index) = match block_range {
+                BlockRange::File(f) => (f.len, f.index),
+                BlockRange::Free { len } => (*len, 0),
+            };
+            (0..len).map(move |_| index)
+        })
+        .enumerate()
+        .map(|(mem_idx, file_idx)| (mem_idx as u64) * file_idx)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day09.txt");
+
+    #[test]
+    fn example() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part_a(&input), Some(1928));
+        assert_eq!(Day09::part_b(&input), Some(2858));
+    }
+
+    #[test]
+    fn moves_last_file_once() {
+        let input = Day09::parse("111").unwrap();
+        assert_eq!(Day09::part_a(&input), Some(1));
+    }
+
+    #[test]
+    fn differential_agrees() {
+        assert_eq!(differential().run(1000, 0), None);
+    }
+}
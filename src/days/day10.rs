@@ -0,0 +1,139 @@
+use crate::{Grid, ParseError, Solution, Vec2};
+use daggy::{Dag, NodeIndex};
+use petgraph::algo::all_simple_paths;
+use petgraph::visit::Dfs;
+use std::collections::HashSet;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_topo_map(input)
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<u64> {
+        let dag = grid_to_dag(grid);
+
+        let start_indices = get_start_indices(grid);
+        let finish_indices_set = get_finish_indices(grid).into_iter().collect::<HashSet<_>>();
+
+        Some(
+            start_indices
+                .iter()
+                .map(|start_index| {
+                    reachable_finish_indices(&dag, *start_index, &finish_indices_set).len() as u64
+                })
+                .sum(),
+        )
+    }
+
+    fn part_b(grid: &Self::Input) -> Option<u64> {
+        let dag = grid_to_dag(grid);
+
+        let start_indices = get_start_indices(grid);
+        let finish_indices = get_finish_indices(grid);
+
+        Some(
+            start_indices
+                .iter()
+                .flat_map(|start_index| {
+                    finish_indices
+                        .iter()
+                        .map(|finish_index| num_paths(&dag, *start_index, *finish_index) as u64)
+                })
+                .sum(),
+        )
+    }
+}
+
+fn parse_topo_map(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_with(input, "a height digit", |_, c| {
+        c.to_digit(10).map(|d| d as u8)
+    })
+}
+
+fn get_paths_from_point(
+    grid: &Grid<u8>,
+    start_point: Vec2,
+) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    let point_height = grid[start_point];
+    grid.neighbors4(start_point)
+        .filter(move |point| grid[*point] == point_height + 1)
+        .map(move |point| (start_point, point))
+}
+
+fn grid_to_dag(grid: &Grid<u8>) -> Dag<(), ()> {
+    let coords_to_idx = |coords: Vec2| grid.index_of(coords).expect("point is on grid") as u32;
+    let edges = grid
+        .positions()
+        .flat_map(|coords| {
+            get_paths_from_point(grid, coords)
+                .map(move |(from, to)| (coords_to_idx(from), coords_to_idx(to), ()))
+        })
+        .collect::<Vec<_>>();
+
+    Dag::from_edges(edges).expect("Is valid graph")
+}
+
+fn get_indices_of_value(grid: &Grid<u8>, value: u8) -> Vec<u32> {
+    grid.positions_of(&value)
+        .map(|pos| grid.index_of(pos).expect("point is on grid") as u32)
+        .collect()
+}
+
+fn get_start_indices(grid: &Grid<u8>) -> Vec<u32> {
+    get_indices_of_value(grid, 0)
+}
+
+fn get_finish_indices(grid: &Grid<u8>) -> Vec<u32> {
+    get_indices_of_value(grid, 9)
+}
+
+fn reachable_finish_indices(
+    dag: &Dag<(), ()>,
+    start_index: u32,
+    finish_indices: &HashSet<u32>,
+) -> Vec<u32> {
+    let graph = dag.graph();
+    let mut dfs = Dfs::new(graph, NodeIndex::new(start_index as usize));
+    let mut reachable_finish_indices = Vec::new();
+
+    while let Some(node) = dfs.next(graph) {
+        let node_idx = node.index() as u32;
+        if finish_indices.contains(&node_idx) {
+            reachable_finish_indices.push(node_idx);
+        }
+    }
+
+    reachable_finish_indices
+}
+
+fn num_paths(dag: &Dag<(), ()>, start_index: u32, finish_index: u32) -> usize {
+    let graph = dag.graph();
+    all_simple_paths::<Vec<_>, _>(
+        graph,
+        NodeIndex::new(start_index as usize),
+        NodeIndex::new(finish_index as usize),
+        0,
+        None,
+    )
+    .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day10.txt");
+
+    #[test]
+    fn example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part_a(&input), Some(36));
+        assert_eq!(Day10::part_b(&input), Some(81));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::differential::{shrink_number, Differential};
+use crate::metrics;
+use crate::{InputLine, ParseError, Solution};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_int_list(input)
+    }
+
+    fn part_a(numbers: &Self::Input) -> Option<u64> {
+        Some(apply_rules_n_times(numbers.clone(), 25).len() as u64)
+    }
+
+    fn part_b(numbers: &Self::Input) -> Option<u64> {
+        let mut cache = HashMap::new();
+        let total_num = count_stones(numbers, 75, &mut cache);
+        metrics::gauge("cache_size", cache.len() as i64);
+        Some(total_num)
+    }
+}
+
+fn parse_int_list(input: &str) -> Result<Vec<u64>, ParseError> {
+    let line = InputLine::enumerate(input)
+        .next()
+        .ok_or_else(|| ParseError::missing_element(0, "stones"))?;
+    line.text
+        .split_whitespace()
+        .map(|number| line.parse_number(number))
+        .collect()
+}
+
+fn apply_rules(numbers: Vec<u64>) -> Vec<u64> {
+    numbers
+        .into_iter()
+        .flat_map(|number| {
+            if number == 0 {
+                return vec![1];
+            }
+
+            let number_string = format!("{}", number);
+            if number_string.len() % 2 == 0 {
+                let half = number_string.len() / 2;
+                let left = number_string[..half].parse::<u64>().unwrap();
+                let right = number_string[half..].parse::<u64>().unwrap();
+                return vec![left, right];
+            }
+
+            vec![number * 2024]
+        })
+        .collect()
+}
+
+fn apply_rules_n_times(numbers: Vec<u64>, n: usize) -> Vec<u64> {
+    (0..n).fold(numbers, |numbers, _| apply_rules(numbers))
+}
+
+fn count_stones(numbers: &[u64], blinks: usize, cache: &mut HashMap<(u64, usize), usize>) -> u64 {
+    numbers
+        .iter()
+        .map(|&num| num_stones_after_n_iterations(blinks, num, cache))
+        .sum::<usize>() as u64
+}
+
+/// Applying the rules to every stone and counting stones without keeping them around have to
+/// agree for any number of blinks.
+pub fn differential() -> Differential<(Vec<u64>, usize), u64> {
+    Differential {
+        generate: |rng| {
+            let stones = (0..rng.range(1..=3)).map(|_| rng.below(10000)).collect();
+            (stones, rng.index(16))
+        },
+        shrink: |(stones, blinks)| {
+            let mut smaller = (0..stones.len())
+                .filter(|_| stones.len() > 1)
+                .map(|idx| ([&stones[..idx], &stones[idx + 1..]].concat(), *blinks))
+                .collect::<Vec<_>>();
+            smaller.extend(
+                shrink_number(*blinks as i64, 0)
+                    .into_iter()
+                    .map(|blinks| (stones.clone(), blinks as usize)),
+            );
+            for (idx, &stone) in stones.iter().enumerate() {
+                for smaller_stone in shrink_number(stone as i64, 0) {
+                    let mut stones = stones.clone();
+                    stones[idx] = smaller_stone as u64;
+                    smaller.push((stones, *blinks));
+                }
+            }
+            smaller
+        },
+        describe: |(stones, blinks)| format!("{} after {blinks} blinks", stones.iter().join(" ")),
+        reference: |(stones, blinks)| apply_rules_n_times(stones.clone(), *blinks).len() as u64,
+        optimized: |(stones, blinks)| count_stones(stones, *blinks, &mut HashMap::new()),
+    }
+}
+
+struct StackElement {
+    total_count_pre: usize,
+    number: u64,
+    remaining_branch: Option<u64>,
+}
+
+fn num_stones_after_n_iterations(
+    n: usize,
+    number: u64,
+    // (num, depth) -> num_stones
+    cache: &mut HashMap<(u64, usize), usize>,
+) -> usize {
+    // The stone itself is the only leaf, there is nothing to pop
+    if n == 0 {
+        return 1;
+    }
+
+    let mut stack = Vec::<StackElement>::with_capacity(75);
+
+    let mut current = number;
+    let mut leaf_count_so_far = 0;
+    loop {
+        let cached = cache.get(&(current, stack.len()));
+        if stack.len() == n || cached.is_some() {
+            leaf_count_so_far += if let Some(cached) = cached {
+                *cached
+            } else {
+                let leaf = stack.pop().expect("stack underflow");
+                let leaf_count = if leaf.remaining_branch.is_some() {
+                    2
+                } else {
+                    1
+                };
+                cache.insert((leaf.number, stack.len()), leaf_count);
+                leaf_count
+            };
+
+            while let Some(&StackElement {
+                remaining_branch: None,
+                ..
+            }) = stack.last()
+            {
+                let stack_elem = stack.pop().expect("stack underflow");
+                cache.insert(
+                    (stack_elem.number, stack.len()),
+                    leaf_count_so_far - stack_elem.total_count_pre,
+                );
+            }
+
+            let Some(last) = stack.last_mut() else {
+                return leaf_count_so_far;
+            };
+
+            current = last.remaining_branch.take().expect("we removed al Nones");
+        }
+
+        if current == 0 {
+            stack.push(StackElement {
+                total_count_pre: leaf_count_so_far,
+                number: current,
+                remaining_branch: None,
+            });
+            current = 1;
+            continue;
+        }
+
+        let num_len = current.ilog10() + 1;
+        if num_len.is_multiple_of(2) {
+            let half = num_len / 2;
+            let left = current / 10u64.pow(half);
+            let right = current % 10u64.pow(half);
+            stack.push(StackElement {
+                total_count_pre: leaf_count_so_far,
+                number: current,
+                remaining_branch: Some(left),
+            });
+            current = right;
+            continue;
+        }
+
+        stack.push(StackElement {
+            total_count_pre: leaf_count_so_far,
+            number: current,
+            remaining_branch: None,
+        });
+        current *= 2024;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day11.txt");
+
+    #[test]
+    fn example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part_a(&input), Some(55312));
+        assert_eq!(Day11::part_b(&input), Some(65601038650482));
+    }
+
+    #[test]
+    fn zero_blinks() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(count_stones(&input, 0, &mut HashMap::new()), 2);
+    }
+
+    #[test]
+    fn differential_agrees() {
+        assert_eq!(differential().run(1000, 0), None);
+    }
+}
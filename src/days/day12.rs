@@ -0,0 +1,140 @@
+use crate::metrics;
+use crate::{Dir4, Grid, ParseError, Solution, Vec2};
+use itertools::Itertools;
+use std::collections::{BTreeSet, HashMap};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(input)
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<u64> {
+        Some(
+            field_dimensions(grid)
+                .values()
+                .map(|dim| (dim.area * dim.fences.len()) as u64)
+                .sum(),
+        )
+    }
+
+    fn part_b(grid: &Self::Input) -> Option<u64> {
+        Some(
+            field_dimensions(grid)
+                .values()
+                .map(|dim| dimension_to_cost(dim) as u64)
+                .sum(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct FieldDimensions {
+    area: usize,
+    fences: Vec<(Dir4, Vec2)>,
+}
+
+fn field_dimensions(grid: &Grid<char>) -> HashMap<(Vec2, char), FieldDimensions> {
+    let mut all_coords = grid.positions().collect::<BTreeSet<_>>();
+    let mut dimensions = HashMap::<(Vec2, char), FieldDimensions>::new();
+
+    while let Some(initial_coords) = all_coords.pop_first() {
+        let current_field_type = grid[initial_coords];
+        let current_dimensions = dimensions
+            .entry((initial_coords, current_field_type))
+            .or_default();
+        let mut fields_to_check_neighbors_of = vec![initial_coords];
+
+        while let Some(check_field) = fields_to_check_neighbors_of.pop() {
+            current_dimensions.area += 1;
+            for neighbor_direction in Dir4::ALL {
+                let neighbor = check_field + neighbor_direction.to_vec2();
+                if grid.get(neighbor) == Some(&current_field_type) {
+                    if all_coords.remove(&neighbor) {
+                        fields_to_check_neighbors_of.push(neighbor);
+                    }
+                } else {
+                    current_dimensions
+                        .fences
+                        .push((neighbor_direction, check_field));
+                }
+            }
+        }
+
+        metrics::count("regions_found", 1);
+        metrics::record("region_area", current_dimensions.area as u64);
+    }
+
+    dimensions
+}
+
+fn dimension_to_cost(dim: &FieldDimensions) -> usize {
+    let sides = {
+        let num_horizontal_segments = {
+            let horizontal_fences = dim
+                .fences
+                .iter()
+                .filter(|(dir, _)| dir.is_vertical())
+                .copied()
+                .into_group_map_by(|(dir, coords)| (*dir, coords.y));
+            horizontal_fences
+                .values()
+                .map(|coords| num_continuous_sections(coords.iter().map(|(_, coords)| coords.x)))
+                .sum::<usize>()
+        };
+        let num_vertical_segments = {
+            let vertical_fences = dim
+                .fences
+                .iter()
+                .filter(|(dir, _)| !dir.is_vertical())
+                .copied()
+                .into_group_map_by(|(dir, coords)| (*dir, coords.x));
+            vertical_fences
+                .values()
+                .map(|coords| num_continuous_sections(coords.iter().map(|(_, coords)| coords.y)))
+                .sum::<usize>()
+        };
+
+        num_horizontal_segments + num_vertical_segments
+    };
+
+    dim.area * sides
+}
+
+fn num_continuous_sections(coords: impl Iterator<Item = i64>) -> usize {
+    let num_breaks = coords
+        .sorted()
+        .tuple_windows()
+        .filter(|(a, b)| a.abs_diff(*b) != 1)
+        .count();
+    num_breaks + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day12.txt");
+
+    #[test]
+    fn example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part_a(&input), Some(1930));
+        assert_eq!(Day12::part_b(&input), Some(1206));
+    }
+
+    #[test]
+    fn records_regions() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        let (_, metrics) = metrics::capture(|| Day12::part_b(&input));
+        assert_eq!(
+            metrics.get("regions_found"),
+            Some(metrics::Metric::Counter(11))
+        );
+    }
+}
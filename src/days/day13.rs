@@ -0,0 +1,290 @@
+use crate::differential::{shrink_number, Differential};
+use crate::rng::Rng;
+use crate::{InputLine, ParseError, ParseErrorKind, Solution, Vec2};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input.lines())
+    }
+
+    fn part_a(machines: &Self::Input) -> Option<u64> {
+        Some(max_prizes_min_tokens(machines, min_price_steps))
+    }
+
+    fn part_b(machines: &Self::Input) -> Option<u64> {
+        let machines = apply_offset(machines.iter().copied());
+        Some(max_prizes_min_tokens(&machines, min_price_steps_cramer))
+    }
+}
+
+const COST_BUTTON_A: u64 = 3;
+const COST_BUTTON_B: u64 = 1;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Machine {
+    a_rel_move: Vec2,
+    b_rel_move: Vec2,
+    prize: Vec2,
+}
+
+const PRIZE_OFFSET: Vec2 = Vec2::new(10000000000000, 10000000000000);
+
+fn parse_input<'a>(lines: impl Iterator<Item = &'a str>) -> Result<Vec<Machine>, ParseError> {
+    let button_regex =
+        regex::Regex::new("Button ([AB]): X\\+([0-9]+), Y\\+([0-9]+)").expect("Valid regex");
+    let parse_button_line = move |line: InputLine| {
+        let cap = button_regex.captures(line.text).ok_or_else(|| {
+            line.error(
+                line.text,
+                ParseErrorKind::UnexpectedToken("'Button <A|B>: X+<x>, Y+<y>'"),
+            )
+        })?;
+        let x = line.parse_number(cap.get(2).unwrap().as_str())?;
+        let y = line.parse_number(cap.get(3).unwrap().as_str())?;
+        Ok(Vec2::new(x, y))
+    };
+
+    let prize_regex = regex::Regex::new("Prize: X=([0-9]+), Y=([0-9]+)").expect("Valid regex");
+    let parse_prize_line = move |line: InputLine| {
+        let cap = prize_regex.captures(line.text).ok_or_else(|| {
+            line.error(
+                line.text,
+                ParseErrorKind::UnexpectedToken("'Prize: X=<x>, Y=<y>'"),
+            )
+        })?;
+        let x = line.parse_number(cap.get(1).unwrap().as_str())?;
+        let y = line.parse_number(cap.get(2).unwrap().as_str())?;
+        Ok(Vec2::new(x, y))
+    };
+
+    let lines = lines
+        .enumerate()
+        .map(|(idx, text)| InputLine::new(idx + 1, text))
+        .collect::<Vec<_>>();
+
+    // Machines are separated by an empty line, which the last one doesn't need
+    lines
+        .chunks(4)
+        .map(|machine| {
+            let line = |idx: usize, element| {
+                machine
+                    .get(idx)
+                    .copied()
+                    .ok_or_else(|| ParseError::missing_element(lines.len(), element))
+            };
+            if let Some(separator) = machine.get(3).filter(|l4| !l4.text.is_empty()) {
+                return Err(separator.error(
+                    separator.text,
+                    ParseErrorKind::UnexpectedToken("an empty line"),
+                ));
+            }
+            Ok(Machine {
+                a_rel_move: parse_button_line(line(0, "button A")?)?,
+                b_rel_move: parse_button_line(line(1, "button B")?)?,
+                prize: parse_prize_line(line(2, "prize")?)?,
+            })
+        })
+        .collect()
+}
+
+/// Finds the button presses needed to win the prize by trying every number of A presses.
+fn min_price_steps(machine: &Machine) -> Option<(u64, u64)> {
+    (0i64..)
+        .take_while(|&a_count| {
+            (machine.a_rel_move * a_count).is_within_bounds_inclusive(machine.prize)
+        })
+        .find_map(|a_count| {
+            let remaining_dist = machine.prize - (machine.a_rel_move * a_count);
+            let b_count: u64 = remaining_dist
+                .divided_by(machine.b_rel_move)?
+                .try_into()
+                .expect("should not be negative");
+            Some((a_count as u64, b_count))
+        })
+}
+
+/// Determinant of the linear equation system of the machine, zero if both buttons move in the
+/// same direction.
+fn determinant(machine: &Machine) -> i64 {
+    machine.a_rel_move.x * machine.b_rel_move.y - machine.b_rel_move.x * machine.a_rel_move.y
+}
+
+/// Finds the button presses needed to win the prize by solving the linear equation system with
+/// Cramer's rule. Machines whose buttons move in the same direction have no unique solution and
+/// are treated as unwinnable.
+fn min_price_steps_cramer(machine: &Machine) -> Option<(u64, u64)> {
+    let xa = machine.a_rel_move.x;
+    let ya = machine.a_rel_move.y;
+    let xb = machine.b_rel_move.x;
+    let yb = machine.b_rel_move.y;
+    let x = machine.prize.x;
+    let y = machine.prize.y;
+
+    let det = determinant(machine);
+    if det == 0 {
+        return None;
+    }
+    let a = (yb * x - xb * y) / det;
+    let b = (xa * y - ya * x) / det;
+
+    if a >= 0 && b >= 0 && x == xa * a + xb * b && y == ya * a + yb * b {
+        Some((a as u64, b as u64))
+    } else {
+        None
+    }
+}
+
+fn max_prizes_min_tokens(
+    machines: &[Machine],
+    min_price_steps_fn: fn(&Machine) -> Option<(u64, u64)>,
+) -> u64 {
+    machines
+        .iter()
+        .filter_map(|machine| {
+            let (a_count, b_count) = min_price_steps_fn(machine)?;
+            Some(a_count * COST_BUTTON_A + b_count * COST_BUTTON_B)
+        })
+        .sum()
+}
+
+/// The brute force of part a and the closed form of part b have to find the same button presses
+/// for every machine.
+pub fn differential() -> Differential<Machine, Option<(u64, u64)>> {
+    Differential {
+        generate: generate_machine,
+        shrink: shrink_machine,
+        describe: |machine| {
+            let Machine {
+                a_rel_move: a,
+                b_rel_move: b,
+                prize,
+            } = machine;
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+                a.x, a.y, b.x, b.y, prize.x, prize.y
+            )
+        },
+        reference: min_price_steps,
+        optimized: min_price_steps_cramer,
+    }
+}
+
+/// Mostly machines whose prize is a combination of the button moves, sometimes with a negative
+/// number of presses, and some with a random prize. The buttons never move in the same
+/// direction, where Cramer's rule doesn't apply.
+fn generate_machine(rng: &mut Rng) -> Machine {
+    let (a_rel_move, b_rel_move) = loop {
+        let a_rel_move = Vec2::new(rng.range(1..=99), rng.range(1..=99));
+        let b_rel_move = Vec2::new(rng.range(1..=99), rng.range(1..=99));
+        if a_rel_move.x * b_rel_move.y != b_rel_move.x * a_rel_move.y {
+            break (a_rel_move, b_rel_move);
+        }
+    };
+    let prize = loop {
+        let prize = if rng.chance(25) {
+            Vec2::new(rng.range(0..=10000), rng.range(0..=10000))
+        } else {
+            a_rel_move * rng.range(-20..=100) + b_rel_move * rng.range(-20..=100)
+        };
+        if prize.x >= 0 && prize.y >= 0 {
+            break prize;
+        }
+    };
+
+    Machine {
+        a_rel_move,
+        b_rel_move,
+        prize,
+    }
+}
+
+fn shrink_machine(machine: &Machine) -> Vec<Machine> {
+    let values = [
+        machine.a_rel_move.x,
+        machine.a_rel_move.y,
+        machine.b_rel_move.x,
+        machine.b_rel_move.y,
+        machine.prize.x,
+        machine.prize.y,
+    ];
+    (0..values.len())
+        .flat_map(|idx| {
+            let min = if idx < 4 { 1 } else { 0 };
+            shrink_number(values[idx], min)
+                .into_iter()
+                .map(move |value| {
+                    let mut values = values;
+                    values[idx] = value;
+                    Machine {
+                        a_rel_move: Vec2::new(values[0], values[1]),
+                        b_rel_move: Vec2::new(values[2], values[3]),
+                        prize: Vec2::new(values[4], values[5]),
+                    }
+                })
+        })
+        .filter(|machine| determinant(machine) != 0)
+        .collect()
+}
+
+fn apply_offset(machines: impl IntoIterator<Item = Machine>) -> Vec<Machine> {
+    machines
+        .into_iter()
+        .map(|machine| Machine {
+            a_rel_move: machine.a_rel_move,
+            b_rel_move: machine.b_rel_move,
+            prize: machine.prize + PRIZE_OFFSET,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("../../inputs/examples/day13.txt");
+
+    #[test]
+    fn example() {
+        let input = Day13::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(Day13::part_a(&input), Some(480));
+        assert_eq!(Day13::part_b(&input), Some(875318608908));
+    }
+
+    #[test]
+    fn last_machine_needs_no_separator() {
+        let input = Day13::parse(SAMPLE_INPUT.trim_end()).unwrap();
+        assert_eq!(input.len(), 4);
+
+        let err = Day13::parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\n").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::MissingElement("prize"));
+        assert_eq!(err.line, 3);
+    }
+
+    #[test]
+    fn differential_agrees() {
+        assert_eq!(differential().run(1000, 0), None);
+    }
+
+    #[test]
+    fn rejects_impossible_presses() {
+        let negative = Machine {
+            a_rel_move: Vec2::new(2, 1),
+            b_rel_move: Vec2::new(1, 2),
+            prize: Vec2::new(1, 5),
+        };
+        assert_eq!(min_price_steps_cramer(&negative), None);
+
+        let collinear = Machine {
+            a_rel_move: Vec2::new(1, 2),
+            b_rel_move: Vec2::new(2, 4),
+            prize: Vec2::new(4, 8),
+        };
+        assert_eq!(min_price_steps_cramer(&collinear), None);
+    }
+}
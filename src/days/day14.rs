@@ -0,0 +1,305 @@
+use crate::settings::Settings;
+use crate::simulation::{Driver, Run, Simulation, Stop};
+use crate::{Grid, InputLine, ParseError, ParseErrorKind, Part, Solution, Vec2};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input.lines())
+    }
+
+    fn part_a(robots: &Self::Input) -> Option<u64> {
+        Some(safety_score_after_n_steps(robots, 100, BOUNDS) as u64)
+    }
+
+    fn part_b(robots: &Self::Input) -> Option<u64> {
+        first_tree_after(robots, BOUNDS).map(|seconds| seconds as u64)
+    }
+
+    /// The example of the puzzle uses a smaller bathroom than the real input.
+    fn solve_with(robots: &Self::Input, part: Part, settings: &Settings) -> Option<u64> {
+        let Options { bounds } =
+            Options::from_settings(settings).expect("settings are checked before solving");
+        match part {
+            Part::A => Some(safety_score_after_n_steps(robots, 100, bounds) as u64),
+            Part::B => first_tree_after(robots, bounds).map(|seconds| seconds as u64),
+        }
+    }
+}
+
+pub const BOUNDS: Vec2 = Vec2::new(101, 103);
+
+/// Rules of the day that may differ from the puzzle, the defaults are the rules of the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Size of the bathroom the robots move in
+    pub bounds: Vec2,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options { bounds: BOUNDS }
+    }
+}
+
+impl Options {
+    /// Reads the options from the keys `width` and `height`, which keep their default if they are
+    /// left out.
+    pub fn from_settings(settings: &Settings) -> Result<Self, String> {
+        let mut options = Options::default();
+        for (key, value) in settings.iter() {
+            let size = value
+                .parse::<i64>()
+                .ok()
+                .filter(|&size| size > 0)
+                .ok_or_else(|| format!("{key} must be a positive number, got '{value}'"));
+            match key {
+                "width" => options.bounds.x = size?,
+                "height" => options.bounds.y = size?,
+                _ => return Err(format!("unknown setting '{key}'")),
+            }
+        }
+        Ok(options)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Robot {
+    position: Vec2,
+    velocity: Vec2,
+}
+
+impl Robot {
+    pub fn position(&self) -> Vec2 {
+        self.position
+    }
+}
+
+/// The robots moving around the bathroom, one second per step. The robots never stop, so the
+/// simulation is never done and has to be run with a step limit or stop predicate.
+pub struct Bathroom {
+    robots: Vec<Robot>,
+    bounds: Vec2,
+    seconds: i64,
+}
+
+impl Bathroom {
+    pub fn new(robots: &[Robot]) -> Self {
+        Self::with_bounds(robots, BOUNDS)
+    }
+
+    fn with_bounds(robots: &[Robot], bounds: Vec2) -> Self {
+        Bathroom {
+            robots: robots.to_vec(),
+            bounds,
+            seconds: 0,
+        }
+    }
+
+    pub fn seconds(&self) -> i64 {
+        self.seconds
+    }
+}
+
+impl Simulation for Bathroom {
+    type State = Vec<Robot>;
+
+    fn state(&self) -> &Vec<Robot> {
+        &self.robots
+    }
+
+    fn step(&mut self) {
+        for robot in &mut self.robots {
+            robot.position = robot.position.wrapping_add(robot.velocity, self.bounds);
+        }
+        self.seconds += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        false
+    }
+
+    /// Every tile shows the number of robots on it, `+` if there are more than nine.
+    fn render(&self) -> Grid<char> {
+        let mut grid = Grid::new(self.bounds.x as usize, self.bounds.y as usize, '.');
+        for (position, count) in self.robots.iter().map(Robot::position).counts() {
+            grid[position] = char::from_digit(count as u32, 10).unwrap_or('+');
+        }
+        grid
+    }
+
+    fn status(&self) -> String {
+        format!("second {}", self.seconds)
+    }
+}
+
+/// Moves the robots for the 100 seconds of part a, or until they form the christmas tree of part
+/// b.
+pub fn simulate(robots: &[Robot], part: Part, driver: &Driver) -> Run {
+    let mut bathroom = Bathroom::new(robots);
+    match part {
+        Part::A => driver.cap_steps(100).run(&mut bathroom),
+        Part::B => {
+            let loop_after = BOUNDS.x * BOUNDS.y;
+            driver
+                .cap_steps(loop_after as usize - 1)
+                .run_until(&mut bathroom, |robots| looks_like_tree(robots, BOUNDS))
+        }
+    }
+}
+
+fn parse_input<'a>(lines: impl Iterator<Item = &'a str>) -> Result<Vec<Robot>, ParseError> {
+    let robot_regex =
+        regex::Regex::new("p=(-?\\d+),(-?\\d+)\\s+v=(-?\\d+),(-?\\d+)").expect("Valid regex");
+    let parse_robot_line = move |line: InputLine| {
+        let cap = robot_regex.captures(line.text).ok_or_else(|| {
+            line.error(
+                line.text,
+                ParseErrorKind::UnexpectedToken("'p=<x>,<y> v=<x>,<y>'"),
+            )
+        })?;
+        let position = {
+            let x = line.parse_number(cap.get(1).unwrap().as_str())?;
+            let y = line.parse_number(cap.get(2).unwrap().as_str())?;
+            Vec2::new(x, y)
+        };
+        let velocity = {
+            let x = line.parse_number(cap.get(3).unwrap().as_str())?;
+            let y = line.parse_number(cap.get(4).unwrap().as_str())?;
+            Vec2::new(x, y)
+        };
+        Ok(Robot { position, velocity })
+    };
+
+    lines
+        .enumerate()
+        .map(|(idx, text)| InputLine::new(idx + 1, text))
+        .filter(|line| !line.text.is_empty())
+        .map(parse_robot_line)
+        .collect()
+}
+
+fn robot_position_after_n_steps(robot: Robot, n: i64, bounds: Vec2) -> Vec2 {
+    robot
+        .position
+        .wrapping_add(robot.velocity.wrapping_mul(n, bounds), bounds)
+}
+
+fn safety_score_after_n_steps(robots: &[Robot], n: i64, bounds: Vec2) -> usize {
+    let coords_to_quadrant = |coords: Vec2| -> Option<u8> {
+        let x_boundary = bounds.x / 2;
+        let y_boundary = bounds.y / 2;
+        let x = if coords.x < x_boundary {
+            0
+        } else if coords.x > x_boundary {
+            1
+        } else {
+            return None;
+        };
+        let y = if coords.y < y_boundary {
+            0
+        } else if coords.y > y_boundary {
+            1
+        } else {
+            return None;
+        };
+        Some(x + y * 2)
+    };
+
+    robots
+        .iter()
+        .map(|r| robot_position_after_n_steps(*r, n, bounds))
+        .counts_by(coords_to_quadrant)
+        .into_iter()
+        .filter_map(|(key, value)| key.map(|_| value))
+        .product::<usize>()
+}
+
+fn tree_heuristic(robot_counts_at_coord: &HashMap<Vec2, usize>, bounds: Vec2) -> bool {
+    const NEIGHBOR_THRESHOLD: usize = 2;
+    const BOT_THRESHOLD_PERCENT: usize = 20;
+    const NEIGHBOR_POSITIONS: [Vec2; 4] = [
+        Vec2::new(1, 0),
+        Vec2::new(-1, 0),
+        Vec2::new(0, 1),
+        Vec2::new(0, -1),
+    ];
+
+    let bot_threshold =
+        (robot_counts_at_coord.values().sum::<usize>() * BOT_THRESHOLD_PERCENT) / 100;
+
+    let robots_with_neighbors = robot_counts_at_coord
+        .keys()
+        .filter(|&&bot| {
+            NEIGHBOR_POSITIONS
+                .iter()
+                .filter(|&&neighbor| {
+                    robot_counts_at_coord.contains_key(&(bot.wrapping_add(neighbor, bounds)))
+                })
+                .count()
+                > NEIGHBOR_THRESHOLD
+        })
+        .count();
+
+    robots_with_neighbors > bot_threshold
+}
+
+fn looks_like_tree(robots: &[Robot], bounds: Vec2) -> bool {
+    tree_heuristic(&robots.iter().map(Robot::position).counts(), bounds)
+}
+
+/// Returns the first second at which the robots look like they form a christmas tree. Robot
+/// positions repeat after `bounds.x * bounds.y` seconds, so there is no point searching further.
+fn first_tree_after(robots: &[Robot], bounds: Vec2) -> Option<i64> {
+    let mut bathroom = Bathroom::with_bounds(robots, bounds);
+    let loop_after = bounds.x * bounds.y;
+    let run = Driver::new()
+        .step_limit(loop_after as usize - 1)
+        .run_until(&mut bathroom, |robots| looks_like_tree(robots, bounds));
+
+    (run.stop == Stop::Predicate).then_some(bathroom.seconds())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day14.txt");
+
+    #[test]
+    fn sample() {
+        let sample_board_size = Vec2::new(11, 7);
+        let robots = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(robots.len(), 12);
+
+        let mut bathroom = Bathroom::with_bounds(&robots, sample_board_size);
+        let run = Driver::new().step_limit(100).run(&mut bathroom);
+        assert_eq!(run.stop, Stop::StepLimit);
+        assert!(bathroom.state().iter().zip(&robots).all(|(moved, robot)| {
+            moved.position() == robot_position_after_n_steps(*robot, 100, sample_board_size)
+        }));
+        assert_eq!(
+            bathroom.render().to_string(),
+            "......2..1.\n...........\n1..........\n.11........\n.....1.....\n...12......\n.1....1....\n"
+        );
+
+        let settings = Settings::from_config("width = 11\nheight = 7").unwrap();
+        assert_eq!(Day14::solve_with(&robots, Part::A, &settings), Some(12));
+        assert_eq!(Day14::solve_with(&robots, Part::B, &settings), None);
+        let settings = Settings::from_config("width = 0").unwrap();
+        assert!(Options::from_settings(&settings).is_err());
+    }
+
+    #[test]
+    fn no_tree() {
+        let robots = Day14::parse("p=0,0 v=1,1\np=5,5 v=-2,3\n").unwrap();
+        assert_eq!(Day14::part_b(&robots), None);
+    }
+}
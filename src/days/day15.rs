@@ -0,0 +1,544 @@
+use crate::simulation::{Driver, Run, Simulation};
+use crate::{Dir4, Grid, InputLine, ParseError, ParseErrorKind, Part, Solution, Vec2};
+use itertools::Itertools;
+use std::fmt::{Debug, Formatter};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = (Map, Vec<Dir4>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input.lines())
+    }
+
+    fn part_a((map, instructions): &Self::Input) -> Option<u64> {
+        let mut map = map.clone();
+        map.transform_map(instructions);
+        Some(map.box_coordinate_sums() as u64)
+    }
+
+    fn part_b((map, instructions): &Self::Input) -> Option<u64> {
+        let mut map = WideMap::from_map(map);
+        map.transform_map(instructions);
+        Some(map.box_coordinate_sums() as u64)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+enum FieldState {
+    Empty,
+    Box,
+    Wall,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+enum WideFieldState {
+    Empty,
+    RBox,
+    LBox,
+    Wall,
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    grid: Grid<FieldState>,
+    robot_coords: Vec2,
+}
+
+impl Map {
+    fn from_lines<'a>(lines: impl Iterator<Item = InputLine<'a>>) -> Result<Self, ParseError> {
+        let mut robot = None;
+        let mut last_line = 0;
+        let lines = lines.inspect(|line| last_line = line.number);
+        let grid = Grid::from_lines(lines, "one of '.', 'O', '#' or '@'", |pos, c| match c {
+            '.' => Some(FieldState::Empty),
+            'O' => Some(FieldState::Box),
+            '#' => Some(FieldState::Wall),
+            '@' => {
+                robot = Some(pos);
+                Some(FieldState::Empty)
+            }
+            _ => None,
+        })?;
+
+        Ok(Map {
+            grid,
+            robot_coords: robot.ok_or_else(|| ParseError::missing_element(last_line, "robot"))?,
+        })
+    }
+
+    fn get_field(&self, coords: Vec2) -> Option<FieldState> {
+        self.grid.get(coords).copied()
+    }
+
+    /// Tries to push the object at the given coordinates in the given direction. Returns if it was successful.
+    fn try_push(&mut self, coords: Vec2, direction: Dir4) -> bool {
+        let field_state = self.get_field(coords).expect("invalid coordinates");
+
+        match field_state {
+            FieldState::Empty => true,
+            FieldState::Wall => false,
+            FieldState::Box => self.try_push_box(coords, direction),
+        }
+    }
+
+    fn try_push_box(&mut self, coords: Vec2, direction: Dir4) -> bool {
+        debug_assert_eq!(
+            self.get_field(coords).expect("invalid coordinates"),
+            FieldState::Box
+        );
+
+        let bounds = self.grid.size();
+        let mut coords_iter = (0i64..)
+            .map(|i| coords + direction.to_vec2() * i)
+            .take_while(|c| c.is_within_bounds(bounds));
+
+        let next_non_box = coords_iter
+            .find(|coords| {
+                let field_state = self.get_field(*coords).expect("invalid coordinates");
+                field_state != FieldState::Box
+            })
+            .expect("no non-box field found");
+
+        match self.get_field(next_non_box) {
+            Some(FieldState::Empty) => {
+                self.grid[coords] = FieldState::Empty;
+                self.grid[next_non_box] = FieldState::Box;
+                true
+            }
+            Some(FieldState::Wall) => false,
+            None | Some(FieldState::Box) => {
+                unreachable!()
+            }
+        }
+    }
+
+    fn transform_map(&mut self, instructions: &[Dir4]) {
+        for &direction in instructions {
+            self.move_robot(direction);
+        }
+    }
+
+    fn box_coordinate_sums(&self) -> usize {
+        self.grid
+            .positions_of(&FieldState::Box)
+            .map(|pos| (pos.y * 100 + pos.x) as usize)
+            .sum::<usize>()
+    }
+}
+
+impl Warehouse for Map {
+    fn robot(&self) -> Vec2 {
+        self.robot_coords
+    }
+
+    fn is_box(&self, coords: Vec2) -> bool {
+        self.get_field(coords) == Some(FieldState::Box)
+    }
+
+    fn move_robot(&mut self, direction: Dir4) -> bool {
+        let new_coords = self.robot_coords + direction.to_vec2();
+        let moved = self.try_push(new_coords, direction);
+        if moved {
+            self.robot_coords = new_coords;
+        }
+        moved
+    }
+
+    fn render(&self) -> Grid<char> {
+        let mut grid = self.grid.map(|field| match field {
+            FieldState::Empty => '.',
+            FieldState::Box => 'O',
+            FieldState::Wall => '#',
+        });
+        grid[self.robot_coords] = '@';
+        grid
+    }
+}
+
+/// The warehouse of part b, where everything except the robot is twice as wide.
+#[derive(Clone)]
+pub struct WideMap {
+    grid: Grid<WideFieldState>,
+    robot_coords: Vec2,
+}
+
+impl WideMap {
+    pub fn from_map(map: &Map) -> Self {
+        let cells = map
+            .grid
+            .iter()
+            .flat_map(|(_, field)| match field {
+                FieldState::Empty => [WideFieldState::Empty, WideFieldState::Empty],
+                FieldState::Box => [WideFieldState::LBox, WideFieldState::RBox],
+                FieldState::Wall => [WideFieldState::Wall, WideFieldState::Wall],
+            })
+            .collect();
+
+        WideMap {
+            grid: Grid::from_cells(map.grid.width() * 2, cells),
+            robot_coords: Vec2::new(map.robot_coords.x * 2, map.robot_coords.y),
+        }
+    }
+
+    fn get_field(&self, coords: Vec2) -> Option<WideFieldState> {
+        self.grid.get(coords).copied()
+    }
+
+    /// Tries to push the object at the given coordinates in the given direction. Returns if it was successful.
+    fn try_push(&mut self, coords: Vec2, direction: Dir4) -> bool {
+        let field_state = self.get_field(coords).expect("invalid coordinates");
+
+        match field_state {
+            WideFieldState::Empty => true,
+            WideFieldState::Wall => false,
+            WideFieldState::LBox | WideFieldState::RBox => match direction {
+                Dir4::North | Dir4::South => self.try_push_box_vertically(coords, direction),
+                Dir4::West | Dir4::East => self.try_push_box_horizontally(coords, direction),
+            },
+        }
+    }
+
+    fn try_push_box_vertically(&mut self, coords: Vec2, direction: Dir4) -> bool {
+        debug_assert!(matches!(direction, Dir4::North | Dir4::South));
+        debug_assert!(matches!(
+            self.get_field(coords).expect("invalid coordinates"),
+            WideFieldState::LBox | WideFieldState::RBox
+        ));
+
+        if self.vert_pushable(coords, direction) {
+            self.vert_push(coords, direction);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn vert_pushable(&self, coords: Vec2, direction: Dir4) -> bool {
+        let box_part = self.get_field(coords).expect("invalid coordinates");
+        let second_coords = if box_part == WideFieldState::LBox {
+            coords + Vec2::new(1, 0)
+        } else {
+            coords + Vec2::new(-1, 0)
+        };
+
+        // TODO: optimize with early return if necessary
+        let primary_push_coords = coords + direction.to_vec2();
+        let primary_pushable = match self.get_field(primary_push_coords).expect("out of bounds") {
+            WideFieldState::Empty => true,
+            WideFieldState::Wall => false,
+            WideFieldState::RBox | WideFieldState::LBox => {
+                self.vert_pushable(primary_push_coords, direction)
+            }
+        };
+
+        let secondary_push_coords = second_coords + direction.to_vec2();
+        let secondary_pushable = match self
+            .get_field(secondary_push_coords)
+            .expect("out of bounds")
+        {
+            WideFieldState::Empty => true,
+            WideFieldState::Wall => false,
+            WideFieldState::RBox | WideFieldState::LBox => {
+                self.vert_pushable(secondary_push_coords, direction)
+            }
+        };
+
+        primary_pushable && secondary_pushable
+    }
+
+    fn vert_push(&mut self, coords: Vec2, direction: Dir4) {
+        let box_part = self.get_field(coords).expect("invalid coordinates");
+        let second_coords = if box_part == WideFieldState::LBox {
+            coords + Vec2::new(1, 0)
+        } else {
+            coords + Vec2::new(-1, 0)
+        };
+
+        let primary_push_coords = coords + direction.to_vec2();
+        let secondary_push_coords = second_coords + direction.to_vec2();
+
+        if matches!(
+            self.get_field(primary_push_coords).expect("out of bounds"),
+            WideFieldState::LBox | WideFieldState::RBox
+        ) {
+            self.vert_push(primary_push_coords, direction);
+        }
+
+        if matches!(
+            self.get_field(secondary_push_coords)
+                .expect("out of bounds"),
+            WideFieldState::LBox | WideFieldState::RBox
+        ) {
+            self.vert_push(secondary_push_coords, direction);
+        }
+
+        self.grid[primary_push_coords] = self.grid[coords];
+        self.grid[coords] = WideFieldState::Empty;
+        self.grid[secondary_push_coords] = self.grid[second_coords];
+        self.grid[second_coords] = WideFieldState::Empty;
+    }
+
+    fn try_push_box_horizontally(&mut self, coords: Vec2, direction: Dir4) -> bool {
+        debug_assert!(matches!(direction, Dir4::West | Dir4::East));
+        debug_assert!(matches!(
+            self.get_field(coords).expect("invalid coordinates"),
+            WideFieldState::LBox | WideFieldState::RBox
+        ));
+
+        let bounds = self.grid.size();
+        let mut coords_iter = (0i64..)
+            .map(|i| (i, coords + direction.to_vec2() * i))
+            .take_while(|(_, c)| c.is_within_bounds(bounds));
+
+        let (dist, next_non_box) = coords_iter
+            .find(|(_dist, coords)| {
+                let field_state = self.get_field(*coords).expect("invalid coordinates");
+                !matches!(field_state, WideFieldState::LBox | WideFieldState::RBox)
+            })
+            .expect("no non-box field found");
+
+        match self.get_field(next_non_box) {
+            Some(WideFieldState::Empty) => {
+                let rev_iter = (0..=dist)
+                    .map(|i| coords + direction.to_vec2() * i)
+                    .rev()
+                    .tuple_windows();
+                for (to, from) in rev_iter {
+                    self.grid[to] = self.grid[from];
+                }
+                self.grid[coords] = WideFieldState::Empty;
+                true
+            }
+            Some(WideFieldState::Wall) => false,
+            None | Some(WideFieldState::LBox) | Some(WideFieldState::RBox) => {
+                unreachable!()
+            }
+        }
+    }
+
+    fn transform_map(&mut self, instructions: &[Dir4]) {
+        for &direction in instructions {
+            self.move_robot(direction);
+        }
+    }
+
+    fn box_coordinate_sums(&self) -> usize {
+        self.grid
+            .positions_of(&WideFieldState::LBox)
+            .map(|pos| (pos.y * 100 + pos.x) as usize)
+            .sum::<usize>()
+    }
+}
+
+impl Warehouse for WideMap {
+    fn robot(&self) -> Vec2 {
+        self.robot_coords
+    }
+
+    fn is_box(&self, coords: Vec2) -> bool {
+        matches!(
+            self.get_field(coords),
+            Some(WideFieldState::LBox | WideFieldState::RBox)
+        )
+    }
+
+    fn move_robot(&mut self, direction: Dir4) -> bool {
+        let new_coords = self.robot_coords + direction.to_vec2();
+        let moved = self.try_push(new_coords, direction);
+        if moved {
+            self.robot_coords = new_coords;
+        }
+        moved
+    }
+
+    fn render(&self) -> Grid<char> {
+        let mut grid = self.grid.map(|field| match field {
+            WideFieldState::Empty => '.',
+            WideFieldState::LBox => '[',
+            WideFieldState::RBox => ']',
+            WideFieldState::Wall => '#',
+        });
+        grid[self.robot_coords] = '@';
+        grid
+    }
+}
+
+impl Debug for WideMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+/// Lets the robot carry out all its instructions, in the narrow warehouse of part a or the wide
+/// one of part b.
+pub fn simulate((map, instructions): &(Map, Vec<Dir4>), part: Part, driver: &Driver) -> Run {
+    match part {
+        Part::A => driver.run(&mut RobotWalk::new(map.clone(), instructions)),
+        Part::B => driver.run(&mut RobotWalk::new(WideMap::from_map(map), instructions)),
+    }
+}
+
+/// A warehouse layout the robot can move around in.
+pub trait Warehouse {
+    fn robot(&self) -> Vec2;
+
+    /// Moves the robot one tile in `direction`, pushing boxes out of the way. Returns if the robot
+    /// moved.
+    fn move_robot(&mut self, direction: Dir4) -> bool;
+
+    fn is_box(&self, coords: Vec2) -> bool;
+
+    fn render(&self) -> Grid<char>;
+}
+
+/// The robot carrying out its instructions in a warehouse, one instruction per step.
+pub struct RobotWalk<W> {
+    warehouse: W,
+    instructions: Vec<Dir4>,
+    next_instruction: usize,
+    /// Number of moves in which the robot pushed at least one box
+    pushes: usize,
+}
+
+impl<W: Warehouse> RobotWalk<W> {
+    pub fn new(warehouse: W, instructions: &[Dir4]) -> Self {
+        RobotWalk {
+            warehouse,
+            instructions: instructions.to_vec(),
+            next_instruction: 0,
+            pushes: 0,
+        }
+    }
+}
+
+impl<W: Warehouse> Simulation for RobotWalk<W> {
+    type State = W;
+
+    fn state(&self) -> &W {
+        &self.warehouse
+    }
+
+    fn step(&mut self) {
+        let direction = self.instructions[self.next_instruction];
+        let target = self.warehouse.robot() + direction.to_vec2();
+        let pushes_box = self.warehouse.is_box(target);
+        if self.warehouse.move_robot(direction) && pushes_box {
+            self.pushes += 1;
+        }
+        self.next_instruction += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.next_instruction == self.instructions.len()
+    }
+
+    fn render(&self) -> Grid<char> {
+        self.warehouse.render()
+    }
+
+    fn status(&self) -> String {
+        let next = match self.instructions.get(self.next_instruction) {
+            Some(direction) => format!(", next move {}", direction.arrow()),
+            None => String::new(),
+        };
+        format!(
+            "robot at {}, {} of {} moves done, {} pushes{next}",
+            self.warehouse.robot(),
+            self.next_instruction,
+            self.instructions.len(),
+            self.pushes
+        )
+    }
+}
+
+fn parse_instructions<'a>(
+    lines: impl Iterator<Item = InputLine<'a>>,
+) -> Result<Vec<Dir4>, ParseError> {
+    lines
+        .flat_map(|line| {
+            line.text.chars().enumerate().map(move |(idx, c)| {
+                Dir4::from_arrow(c).ok_or_else(|| {
+                    line.char_error(
+                        idx,
+                        c,
+                        ParseErrorKind::UnexpectedToken("a move instruction"),
+                    )
+                })
+            })
+        })
+        .collect()
+}
+
+fn parse_input<'a>(lines: impl Iterator<Item = &'a str>) -> Result<(Map, Vec<Dir4>), ParseError> {
+    let mut lines = lines
+        .enumerate()
+        .map(|(idx, text)| InputLine::new(idx + 1, text));
+    let map = Map::from_lines(&mut lines.by_ref().take_while(|line| !line.text.is_empty()))?;
+    let instructions = parse_instructions(lines)?;
+    Ok((map, instructions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::Stop;
+
+    const LARGE_EXAMPLE: &str = include_str!("../../inputs/examples/day15.txt");
+
+    #[test]
+    fn test_example_small() {
+        let input = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+        let (mut map, instructions) = parse_input(input.lines()).unwrap();
+        map.transform_map(&instructions);
+        assert_eq!(map.box_coordinate_sums(), 2028);
+    }
+
+    #[test]
+    fn example() {
+        let input = Day15::parse(LARGE_EXAMPLE).unwrap();
+        assert_eq!(Day15::part_a(&input), Some(10092));
+        assert_eq!(Day15::part_b(&input), Some(9021));
+    }
+
+    #[test]
+    fn simulated_robot_walk() {
+        let (map, instructions) = parse_input(LARGE_EXAMPLE.lines()).unwrap();
+        let mut walk = RobotWalk::new(WideMap::from_map(&map), &instructions);
+        assert_eq!(
+            walk.render().row(4).iter().collect::<String>(),
+            "##....[]@.....[]..##"
+        );
+
+        let run = Driver::new().record_every(100).run(&mut walk);
+        assert_eq!(run.stop, Stop::Done);
+        assert_eq!(run.steps, instructions.len());
+        assert_eq!(run.recording.frames().len(), 8);
+        assert_eq!(walk.state().box_coordinate_sums(), 9021);
+        assert_eq!(
+            run.recording
+                .frames()
+                .last()
+                .unwrap()
+                .grid
+                .row(1)
+                .iter()
+                .collect::<String>(),
+            "##[].......[].[][]##"
+        );
+    }
+}
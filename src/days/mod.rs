@@ -0,0 +1,324 @@
+use crate::bench::millis;
+use crate::differential::Divergence;
+use crate::external::ExternalError;
+use crate::json::Json;
+use crate::metrics::{self, Metrics};
+use crate::settings::Settings;
+use crate::simulation::{Driver, DynSimulation, Run};
+use crate::{ParseError, Part, Solution};
+use std::fmt::Display;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Declares the day modules, [`DAYS`] and the dispatch of [`run_with`] from one list of
+/// `<day> => <module>::<solution>` entries, sorted by day. `aoc24 new` adds new days to it.
+macro_rules! days {
+    ($($day:literal => $module:ident::$solution:ident,)*) => {
+        $(pub mod $module;)*
+
+        /// All days that have a solution, in order.
+        pub const DAYS: &[u8] = &[$($day),*];
+
+        fn dispatch(
+            day: u8,
+            input: &str,
+            parts: &[Part],
+            settings: &Settings,
+        ) -> Option<Result<RunResult, ParseError>> {
+            match day {
+                $($day => Some(run_solution::<$module::$solution>(input, parts, settings)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+days! {
+    1 => day01::Day01,
+    2 => day02::Day02,
+    3 => day03::Day03,
+    4 => day04::Day04,
+    5 => day05::Day05,
+    6 => day06::Day06,
+    7 => day07::Day07,
+    8 => day08::Day08,
+    9 => day09::Day09,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+}
+
+/// Days whose solution is a simulation that can be rendered.
+pub const SIMULATIONS: &[u8] = &[6, 14, 15];
+
+/// Days that can solve inputs larger than memory, see [`run_external`].
+pub const EXTERNAL: &[u8] = &[1];
+
+/// Days that can explain their answers with a report, see [`report`].
+pub const REPORTS: &[u8] = &[1, 2];
+
+/// Days whose rules can be changed with settings, see [`check_settings`].
+pub const CONFIGURABLE: &[u8] = &[2, 14];
+
+/// Days with two implementations that are checked against each other by [`differential`].
+pub const DIFFERENTIALS: &[u8] = &[9, 11, 13];
+
+/// Answers of a single run together with how long parsing and solving took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunResult {
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: Part,
+    /// `None` if the input has no answer for the part
+    pub answer: Option<u64>,
+    pub solve_time: Duration,
+    /// Recorded by the solver while solving the part
+    pub metrics: Metrics,
+}
+
+impl PartResult {
+    /// The answer, or `none` if the input has no answer.
+    pub fn answer_text(&self) -> String {
+        self.answer
+            .map_or_else(|| "none".to_owned(), |answer| answer.to_string())
+    }
+}
+
+impl RunResult {
+    /// The answer of `part`, `None` if it wasn't solved or has no answer.
+    pub fn answer(&self, part: Part) -> Option<u64> {
+        self.parts
+            .iter()
+            .find(|result| result.part == part)
+            .and_then(|result| result.answer)
+    }
+
+    /// One JSON object per solved part with the answer and timings.
+    pub fn to_json(&self, day: u8) -> Vec<Json> {
+        self.parts
+            .iter()
+            .map(|result| {
+                Json::object([
+                    ("day", day.into()),
+                    ("part", result.part.to_string().into()),
+                    ("answer", result.answer.into()),
+                    ("parse_ms", millis(self.parse_time).into()),
+                    ("solve_ms", millis(result.solve_time).into()),
+                    ("extra_metrics", result.metrics.to_json()),
+                ])
+            })
+            .collect()
+    }
+}
+
+fn run_solution<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    settings: &Settings,
+) -> Result<RunResult, ParseError> {
+    let parse_start = Instant::now();
+    let input = S::parse(input).map_err(|e| e.in_day(S::DAY))?;
+    let parse_time = parse_start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let solve_start = Instant::now();
+            let (answer, metrics) = metrics::capture(|| S::solve_with(&input, part, settings));
+            PartResult {
+                part,
+                answer,
+                solve_time: solve_start.elapsed(),
+                metrics,
+            }
+        })
+        .collect();
+
+    Ok(RunResult { parse_time, parts })
+}
+
+/// Parses the input of the given day and solves the requested parts. Returns `None` if there is
+/// no solution for that day.
+pub fn run(day: u8, input: &str, parts: &[Part]) -> Option<Result<RunResult, ParseError>> {
+    run_with(day, input, parts, &Settings::default())
+}
+
+/// Like [`run`], but with settings that differ from the rules of the puzzles. The settings have to
+/// pass [`check_settings`] for the day.
+pub fn run_with(
+    day: u8,
+    input: &str,
+    parts: &[Part],
+    settings: &Settings,
+) -> Option<Result<RunResult, ParseError>> {
+    dispatch(day, input, parts, settings)
+}
+
+/// Like [`run`], but for inputs that don't fit into memory. The input is read line by line and
+/// at most `run_len` numbers per list are kept in memory, the rest is spilled to `temp_dir`. The
+/// parse time includes spilling. Returns `None` if the day isn't one of the [`EXTERNAL`] days.
+pub fn run_external(
+    day: u8,
+    lines: impl Iterator<Item = std::io::Result<String>>,
+    parts: &[Part],
+    temp_dir: &Path,
+    run_len: usize,
+) -> Option<Result<RunResult, ExternalError>> {
+    if day != 1 {
+        return None;
+    }
+
+    let run = || {
+        let parse_start = Instant::now();
+        let lists = day01::ExternalLists::spill(lines, temp_dir, run_len)?;
+        let parse_time = parse_start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let solve_start = Instant::now();
+                let answer = match part {
+                    Part::A => lists.total_distance()?,
+                    Part::B => lists.similarity()?,
+                };
+                Ok(PartResult {
+                    part,
+                    answer: Some(answer),
+                    solve_time: solve_start.elapsed(),
+                    metrics: Metrics::default(),
+                })
+            })
+            .collect::<Result<_, ExternalError>>()?;
+
+        Ok(RunResult { parse_time, parts })
+    };
+
+    Some(run().map_err(|e| match e {
+        ExternalError::Parse(e) => ExternalError::Parse(e.in_day(day)),
+        e => e,
+    }))
+}
+
+fn parse_for<S: Solution>(input: &str) -> Result<S::Input, ParseError> {
+    S::parse(input).map_err(|e| e.in_day(S::DAY))
+}
+
+/// Parses the input of the given day and runs its simulation for `part` with `driver`. Returns
+/// `None` if the day isn't one of the [`SIMULATIONS`].
+pub fn simulate(
+    day: u8,
+    input: &str,
+    part: Part,
+    driver: &Driver,
+) -> Option<Result<Run, ParseError>> {
+    let run = match day {
+        6 => parse_for::<day06::Day06>(input).map(|lab| day06::simulate(&lab, driver)),
+        14 => parse_for::<day14::Day14>(input).map(|robots| day14::simulate(&robots, part, driver)),
+        15 => parse_for::<day15::Day15>(input).map(|input| day15::simulate(&input, part, driver)),
+        _ => return None,
+    };
+
+    Some(run)
+}
+
+/// Parses the input of the given day and sets up its simulation for `part` to be stepped through
+/// by hand. Returns `None` if the day isn't one of the [`SIMULATIONS`].
+pub fn simulation(
+    day: u8,
+    input: &str,
+    part: Part,
+) -> Option<Result<Box<dyn DynSimulation>, ParseError>> {
+    let simulation = match day {
+        6 => parse_for::<day06::Day06>(input)
+            .map(|lab| Box::new(day06::Patrol::new(&lab)) as Box<dyn DynSimulation>),
+        14 => parse_for::<day14::Day14>(input)
+            .map(|robots| Box::new(day14::Bathroom::new(&robots)) as Box<dyn DynSimulation>),
+        15 => parse_for::<day15::Day15>(input).map(|(map, instructions)| match part {
+            Part::A => {
+                Box::new(day15::RobotWalk::new(map, &instructions)) as Box<dyn DynSimulation>
+            }
+            Part::B => Box::new(day15::RobotWalk::new(
+                day15::WideMap::from_map(&map),
+                &instructions,
+            )),
+        }),
+        _ => return None,
+    };
+
+    Some(simulation)
+}
+
+/// Runs the differential test of the given day on `cases` generated inputs and returns the first
+/// divergence. Returns `None` if the day isn't one of the [`DIFFERENTIALS`].
+pub fn differential(day: u8, cases: usize, seed: u64) -> Option<Option<Divergence>> {
+    let divergence = match day {
+        9 => day09::differential().run(cases, seed),
+        11 => day11::differential().run(cases, seed),
+        13 => day13::differential().run(cases, seed),
+        _ => return None,
+    };
+
+    Some(divergence)
+}
+
+/// Breakdown of the answers of a day, as table or JSON.
+pub trait DayReport: Display {
+    fn to_json(&self) -> Json;
+}
+
+/// Checks that the day knows all `settings` and that their values are valid. Days that aren't
+/// [`CONFIGURABLE`] accept no settings.
+pub fn check_settings(day: u8, settings: &Settings) -> Result<(), String> {
+    match day {
+        2 => day02::Options::from_settings(settings).map(drop),
+        14 => day14::Options::from_settings(settings).map(drop),
+        _ if settings.is_empty() => Ok(()),
+        _ => Err(format!("day {day} has no settings")),
+    }
+}
+
+/// Settings of the reports, each day uses the ones that apply to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportOptions {
+    /// Number of entries to list where there is a ranking
+    pub top_k: usize,
+    /// Rules of the day, they have to pass [`check_settings`]
+    pub settings: Settings,
+}
+
+impl Default for ReportOptions {
+    fn default() -> Self {
+        ReportOptions {
+            top_k: 10,
+            settings: Settings::default(),
+        }
+    }
+}
+
+/// Parses the input of the given day and breaks its answers down. Returns `None` if the day isn't
+/// one of the [`REPORTS`].
+pub fn report(
+    day: u8,
+    input: &str,
+    options: &ReportOptions,
+) -> Option<Result<Box<dyn DayReport>, ParseError>> {
+    let report = match day {
+        1 => parse_for::<day01::Day01>(input)
+            .map(|(a, b)| Box::new(day01::report(&a, &b, options.top_k)) as Box<dyn DayReport>),
+        2 => parse_for::<day02::Day02>(input).map(|reports| {
+            let day_options = day02::Options::from_settings(&options.settings)
+                .expect("settings are checked before reporting");
+            Box::new(day02::report(&reports, &day_options)) as Box<dyn DayReport>
+        }),
+        _ => return None,
+    };
+
+    Some(report)
+}
@@ -0,0 +1,164 @@
+use crate::batch::panic_message;
+use crate::rng::Rng;
+use std::fmt::{Debug, Display, Formatter};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Two implementations of the same computation, usually a naive and an optimized one, that have
+/// to agree on every input `generate` can produce.
+pub struct Differential<I, O> {
+    pub generate: fn(&mut Rng) -> I,
+    /// Smaller variants of an input, tried in order when minimizing a diverging input
+    pub shrink: fn(&I) -> Vec<I>,
+    /// The input in a form that can be pasted into a puzzle input or test
+    pub describe: fn(&I) -> String,
+    pub reference: fn(&I) -> O,
+    pub optimized: fn(&I) -> O,
+}
+
+/// The first input both implementations disagreed on, after minimizing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// Index of the generated case that diverged first
+    pub case: usize,
+    /// Number of times the input was successfully shrunk
+    pub shrinks: usize,
+    pub input: String,
+    pub reference: String,
+    pub optimized: String,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "case {} diverged, minimized in {} steps to:",
+            self.case, self.shrinks
+        )?;
+        writeln!(f, "{}", self.input.trim_end())?;
+        writeln!(f, "reference: {}", self.reference)?;
+        write!(f, "optimized: {}", self.optimized)
+    }
+}
+
+/// Result of one implementation, a panic counts as a result so it can be compared as well.
+type Outcome<O> = Result<O, String>;
+
+fn outcome<I, O>(f: fn(&I) -> O, input: &I) -> Outcome<O> {
+    catch_unwind(AssertUnwindSafe(|| f(input))).map_err(|payload| panic_message(payload.as_ref()))
+}
+
+fn describe_outcome<O: Debug>(outcome: &Outcome<O>) -> String {
+    match outcome {
+        Ok(output) => format!("{output:?}"),
+        Err(msg) => format!("panicked: {msg}"),
+    }
+}
+
+impl<I, O: PartialEq + Debug> Differential<I, O> {
+    /// Both outcomes if the implementations disagree on `input`.
+    fn diverges(&self, input: &I) -> Option<(Outcome<O>, Outcome<O>)> {
+        let reference = outcome(self.reference, input);
+        let optimized = outcome(self.optimized, input);
+        (reference != optimized).then_some((reference, optimized))
+    }
+
+    /// Runs both implementations on `cases` inputs generated from `seed` and returns the first
+    /// divergence, minimized by greedily taking the first smaller input that still diverges.
+    pub fn run(&self, cases: usize, seed: u64) -> Option<Divergence> {
+        let mut rng = Rng::new(seed);
+        let (case, mut input, mut outcomes) = (0..cases).find_map(|case| {
+            let input = (self.generate)(&mut rng);
+            self.diverges(&input)
+                .map(|outcomes| (case, input, outcomes))
+        })?;
+
+        let mut shrinks = 0;
+        while let Some((smaller, smaller_outcomes)) = (self.shrink)(&input)
+            .into_iter()
+            .find_map(|smaller| self.diverges(&smaller).map(|outcomes| (smaller, outcomes)))
+        {
+            input = smaller;
+            outcomes = smaller_outcomes;
+            shrinks += 1;
+        }
+
+        Some(Divergence {
+            case,
+            shrinks,
+            input: (self.describe)(&input),
+            reference: describe_outcome(&outcomes.0),
+            optimized: describe_outcome(&outcomes.1),
+        })
+    }
+}
+
+/// Smaller variants of a number for shrinking: zero, half and one less, all at least `min`.
+pub fn shrink_number(n: i64, min: i64) -> Vec<i64> {
+    let mut smaller = vec![min, min + (n - min) / 2, n - 1];
+    smaller.retain(|&candidate| candidate >= min && candidate < n);
+    smaller.dedup();
+    smaller
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers_differential(optimized: fn(&Vec<u64>) -> u64) -> Differential<Vec<u64>, u64> {
+        Differential {
+            generate: |rng| (0..rng.range(1..=10)).map(|_| rng.below(100)).collect(),
+            shrink: |numbers| {
+                let mut smaller = (0..numbers.len())
+                    .map(|idx| [&numbers[..idx], &numbers[idx + 1..]].concat())
+                    .collect::<Vec<_>>();
+                for (idx, &n) in numbers.iter().enumerate() {
+                    for candidate in shrink_number(n as i64, 0) {
+                        let mut numbers = numbers.clone();
+                        numbers[idx] = candidate as u64;
+                        smaller.push(numbers);
+                    }
+                }
+                smaller
+            },
+            describe: |numbers| format!("{numbers:?}"),
+            reference: |numbers| numbers.iter().sum(),
+            optimized,
+        }
+    }
+
+    #[test]
+    fn finds_and_minimizes_divergence() {
+        assert_eq!(
+            numbers_differential(|numbers| numbers.iter().sum()).run(1000, 1),
+            None
+        );
+
+        // Wrong as soon as there is a number larger than 9
+        let sum_digits = |numbers: &Vec<u64>| numbers.iter().map(|n| n % 10).sum();
+        let divergence = numbers_differential(sum_digits).run(1000, 1).unwrap();
+        assert_eq!(divergence.input, "[10]");
+        assert_eq!(divergence.reference, "10");
+        assert_eq!(divergence.optimized, "0");
+    }
+
+    #[test]
+    fn panics_diverge() {
+        let differential = Differential {
+            optimized: |numbers: &Vec<u64>| numbers[1],
+            reference: |numbers: &Vec<u64>| numbers.get(1).copied().unwrap_or_default(),
+            ..numbers_differential(|numbers| numbers.iter().sum())
+        };
+        let divergence = differential.run(1000, 1).unwrap();
+        assert_eq!(divergence.input, "[]");
+        assert!(divergence
+            .optimized
+            .starts_with("panicked: index out of bounds"));
+    }
+
+    #[test]
+    fn shrinks_numbers() {
+        assert_eq!(shrink_number(10, 0), vec![0, 5, 9]);
+        assert_eq!(shrink_number(2, 1), vec![1]);
+        assert_eq!(shrink_number(1, 1), Vec::<i64>::new());
+    }
+}
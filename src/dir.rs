@@ -0,0 +1,217 @@
+use crate::Vec2;
+use std::str::FromStr;
+
+/// One of the four orthogonal directions on a grid where `y` grows downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    /// All directions in clockwise order, starting with north.
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    /// Parses one of the arrows `^`, `>`, `v` and `<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Dir4::North),
+            '>' => Some(Dir4::East),
+            'v' => Some(Dir4::South),
+            '<' => Some(Dir4::West),
+            _ => None,
+        }
+    }
+
+    /// Parses one of the compass letters `N`, `E`, `S` and `W`.
+    pub fn from_letter(c: char) -> Option<Self> {
+        match c {
+            'N' => Some(Dir4::North),
+            'E' => Some(Dir4::East),
+            'S' => Some(Dir4::South),
+            'W' => Some(Dir4::West),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Dir4::North => '^',
+            Dir4::East => '>',
+            Dir4::South => 'v',
+            Dir4::West => '<',
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Returns whether moving in this direction changes `y`.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::North | Dir4::South)
+    }
+
+    /// Offset of a single step in this direction.
+    pub fn to_vec2(self) -> Vec2 {
+        match self {
+            Dir4::North => Vec2::new(0, -1),
+            Dir4::East => Vec2::new(1, 0),
+            Dir4::South => Vec2::new(0, 1),
+            Dir4::West => Vec2::new(-1, 0),
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = String;
+
+    /// Accepts both an arrow and a compass letter, see [`Dir4::from_arrow`] and
+    /// [`Dir4::from_letter`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir4::from_arrow(c).or_else(|| Dir4::from_letter(c)),
+            _ => None,
+        }
+        .ok_or_else(|| format!("invalid direction '{s}', expected one of ^>v< or NESW"))
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions on a grid where `y` grows downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// All directions in clockwise order, starting with north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// Turns by 45° clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45° counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        (self as usize) % 2 == 1
+    }
+
+    /// Offset of a single step in this direction.
+    pub fn to_vec2(self) -> Vec2 {
+        match self {
+            Dir8::North => Vec2::new(0, -1),
+            Dir8::NorthEast => Vec2::new(1, -1),
+            Dir8::East => Vec2::new(1, 0),
+            Dir8::SouthEast => Vec2::new(1, 1),
+            Dir8::South => Vec2::new(0, 1),
+            Dir8::SouthWest => Vec2::new(-1, 1),
+            Dir8::West => Vec2::new(-1, 0),
+            Dir8::NorthWest => Vec2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::North => Dir8::North,
+            Dir4::East => Dir8::East,
+            Dir4::South => Dir8::South,
+            Dir4::West => Dir8::West,
+        }
+    }
+}
+
+impl FromStr for Dir8 {
+    type Err = String;
+
+    /// Accepts the compass names `N`, `NE`, `E`, `SE`, `S`, `SW`, `W` and `NW` as well as the
+    /// arrows of [`Dir4::from_arrow`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Dir8::NorthEast),
+            "SE" => Ok(Dir8::SouthEast),
+            "SW" => Ok(Dir8::SouthWest),
+            "NW" => Ok(Dir8::NorthWest),
+            _ => s.parse::<Dir4>().map(Dir8::from).map_err(|_| {
+                format!("invalid direction '{s}', expected a compass name like NE or one of ^>v<")
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.turn_right().to_vec2(), dir.to_vec2().rotate_right());
+            assert_eq!(dir.reverse().to_vec2(), -dir.to_vec2());
+        }
+        for dir in Dir8::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.reverse().to_vec2(), -dir.to_vec2());
+            assert_eq!(
+                dir.is_diagonal(),
+                dir.to_vec2().x != 0 && dir.to_vec2().y != 0
+            );
+        }
+        assert_eq!(Dir8::North.turn_right(), Dir8::NorthEast);
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Dir4::from_arrow('v'), Some(Dir4::South));
+        assert_eq!(Dir4::from_arrow('S'), None);
+        assert_eq!("W".parse::<Dir4>(), Ok(Dir4::West));
+        assert_eq!("^".parse::<Dir4>(), Ok(Dir4::North));
+        assert!("NE".parse::<Dir4>().is_err());
+        assert_eq!("NE".parse::<Dir8>(), Ok(Dir8::NorthEast));
+        assert_eq!("<".parse::<Dir8>(), Ok(Dir8::West));
+        assert!("X".parse::<Dir8>().is_err());
+        assert!(Dir4::ALL
+            .iter()
+            .all(|dir| Dir4::from_arrow(dir.arrow()) == Some(*dir)));
+    }
+}
@@ -0,0 +1,213 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Error returned by all input parsers. Line and column are 1-based and point at the start of the
+/// offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Day whose input failed to parse. Filled in by the runner, parsers shared between days
+    /// don't know it.
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The token is not a valid number of the expected type.
+    InvalidNumber,
+    /// The line ended before the named element could be read.
+    MissingToken(&'static str),
+    /// The token doesn't fit what the parser expected at this position.
+    UnexpectedToken(&'static str),
+    /// The line has a different length than the lines before it.
+    RaggedRow { expected_len: usize },
+    /// The line has a different number of fields than the table has columns.
+    ColumnCount { expected: usize, found: usize },
+    /// The named element doesn't appear anywhere in the input.
+    MissingElement(&'static str),
+    /// The underlying reader failed.
+    Io(String),
+}
+
+impl ParseError {
+    pub fn new(kind: ParseErrorKind, line: usize, column: usize, token: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            line,
+            column,
+            token: token.into(),
+            kind,
+        }
+    }
+
+    /// Error for an element that is missing from the input as a whole. Points just past the last
+    /// line that was read.
+    pub fn missing_element(last_line: usize, element: &'static str) -> Self {
+        Self::new(
+            ParseErrorKind::MissingElement(element),
+            last_line + 1,
+            1,
+            "",
+        )
+    }
+
+    pub fn io(line: usize, err: std::io::Error) -> Self {
+        Self::new(ParseErrorKind::Io(err.to_string()), line, 1, "")
+    }
+
+    pub fn in_day(self, day: u8) -> Self {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+
+        match &self.kind {
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number '{}'", self.token),
+            ParseErrorKind::MissingToken(expected) => write!(f, "missing {expected}"),
+            ParseErrorKind::UnexpectedToken(expected) => {
+                write!(f, "expected {expected}, found '{}'", self.token)
+            }
+            ParseErrorKind::RaggedRow { expected_len } => write!(
+                f,
+                "expected a row of length {expected_len}, found length {}",
+                self.token.chars().count()
+            ),
+            ParseErrorKind::ColumnCount { expected, found } => {
+                write!(f, "expected {expected} columns, found {found}")
+            }
+            ParseErrorKind::MissingElement(element) => write!(f, "no {element} found in input"),
+            ParseErrorKind::Io(err) => write!(f, "failed to read input: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A single line of puzzle input together with its 1-based line number, used to attach positions
+/// to parse errors.
+#[derive(Debug, Clone, Copy)]
+pub struct InputLine<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        InputLine { number, text }
+    }
+
+    pub fn enumerate(input: &'a str) -> impl Iterator<Item = InputLine<'a>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, text)| InputLine::new(idx + 1, text))
+    }
+
+    /// 1-based column at which `token` starts. `token` has to be a sub-slice of this line,
+    /// otherwise the column of the line end is returned.
+    pub fn column_of(&self, token: &str) -> usize {
+        let line_start = self.text.as_ptr() as usize;
+        let byte_offset = (token.as_ptr() as usize)
+            .checked_sub(line_start)
+            .filter(|offset| *offset <= self.text.len())
+            .unwrap_or(self.text.len());
+
+        self.text[..byte_offset].chars().count() + 1
+    }
+
+    /// Column just past the end of the line, where missing tokens are reported.
+    pub fn end_column(&self) -> usize {
+        self.text.chars().count() + 1
+    }
+
+    pub fn error(&self, token: &str, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(kind, self.number, self.column_of(token), token)
+    }
+
+    /// Error for the character at the given 0-based character index of this line.
+    pub fn char_error(&self, char_idx: usize, c: char, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(kind, self.number, char_idx + 1, c)
+    }
+
+    pub fn parse_number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token
+            .parse()
+            .map_err(|_| self.error(token, ParseErrorKind::InvalidNumber))
+    }
+
+    /// Unwraps the next token of this line, reporting a missing `expected` element otherwise.
+    pub fn expect_token(
+        &self,
+        token: Option<&'a str>,
+        expected: &'static str,
+    ) -> Result<&'a str, ParseError> {
+        token.ok_or_else(|| {
+            ParseError::new(
+                ParseErrorKind::MissingToken(expected),
+                self.number,
+                self.end_column(),
+                "",
+            )
+        })
+    }
+
+    /// Checks that this line is as long as the previous rows of a grid.
+    pub fn expect_row_len(&self, expected_len: usize) -> Result<(), ParseError> {
+        if self.text.chars().count() == expected_len {
+            Ok(())
+        } else {
+            Err(ParseError::new(
+                ParseErrorKind::RaggedRow { expected_len },
+                self.number,
+                1,
+                self.text,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_token_position() {
+        let line = InputLine::new(3, "12   x4");
+        let token = line.text.split_whitespace().nth(1).unwrap();
+        let err = line.parse_number::<u64>(token).unwrap_err();
+
+        assert_eq!(err.line, 3);
+        assert_eq!(err.column, 6);
+        assert_eq!(err.token, "x4");
+        assert_eq!(err.kind, ParseErrorKind::InvalidNumber);
+        assert_eq!(
+            err.in_day(1).to_string(),
+            "day 1, line 3, column 6: invalid number 'x4'"
+        );
+    }
+
+    #[test]
+    fn reports_missing_token_at_line_end() {
+        let line = InputLine::new(1, "12");
+        let mut parts = line.text.split_whitespace();
+        line.expect_token(parts.next(), "first number").unwrap();
+        let err = line
+            .expect_token(parts.next(), "second number")
+            .unwrap_err();
+
+        assert_eq!(err.column, 3);
+        assert_eq!(err.kind, ParseErrorKind::MissingToken("second number"));
+    }
+}
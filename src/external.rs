@@ -0,0 +1,252 @@
+use crate::ParseError;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Number of runs merged at once, more runs are first merged into longer ones to not run out of
+/// file handles.
+pub const DEFAULT_FAN_IN: usize = 64;
+
+/// A temporary directory for spilled runs that is removed again when dropped.
+#[derive(Debug)]
+struct SpillDir {
+    path: PathBuf,
+}
+
+impl SpillDir {
+    fn create(parent: &Path) -> std::io::Result<Self> {
+        static CREATED: AtomicUsize = AtomicUsize::new(0);
+        let path = parent.join(format!(
+            "aoc24-spill-{}-{}",
+            std::process::id(),
+            CREATED.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&path)?;
+        Ok(SpillDir { path })
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Sorts more numbers than fit into memory. Numbers are buffered until there are `run_len` of
+/// them, then the run is sorted and spilled to a temporary file. [`ExternalSorter::finish`]
+/// merges the runs back together.
+#[derive(Debug)]
+pub struct ExternalSorter {
+    run_len: usize,
+    fan_in: usize,
+    buffer: Vec<u64>,
+    dir: SpillDir,
+    runs: Vec<PathBuf>,
+    /// Number of run files written so far, used to name the next one
+    written: usize,
+}
+
+impl ExternalSorter {
+    /// Spills runs of `run_len` numbers into a new directory inside of `parent`.
+    pub fn new(parent: &Path, run_len: usize) -> std::io::Result<Self> {
+        assert!(run_len > 0, "runs can't be empty");
+        Ok(ExternalSorter {
+            run_len,
+            fan_in: DEFAULT_FAN_IN,
+            buffer: Vec::with_capacity(run_len),
+            dir: SpillDir::create(parent)?,
+            runs: vec![],
+            written: 0,
+        })
+    }
+
+    pub fn with_fan_in(self, fan_in: usize) -> Self {
+        assert!(fan_in > 1, "merging needs at least two runs at once");
+        ExternalSorter { fan_in, ..self }
+    }
+
+    pub fn push(&mut self, value: u64) -> std::io::Result<()> {
+        self.buffer.push(value);
+        if self.buffer.len() == self.run_len {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn next_run_path(&mut self) -> PathBuf {
+        self.written += 1;
+        self.dir.path.join(format!("run-{}", self.written))
+    }
+
+    fn spill(&mut self) -> std::io::Result<()> {
+        self.buffer.sort_unstable();
+        let path = self.next_run_path();
+        write_run(&path, self.buffer.drain(..).map(Ok))?;
+        self.runs.push(path);
+        Ok(())
+    }
+
+    /// Spills the remaining numbers and merges runs until at most `fan_in` are left.
+    pub fn finish(mut self) -> std::io::Result<SortedRuns> {
+        if !self.buffer.is_empty() {
+            self.spill()?;
+        }
+
+        let mut runs = std::mem::take(&mut self.runs);
+        while runs.len() > self.fan_in {
+            let mut merged_runs = Vec::with_capacity(runs.len().div_ceil(self.fan_in));
+            for group in runs.chunks(self.fan_in) {
+                let path = self.next_run_path();
+                write_run(&path, Merge::open(group)?)?;
+                for run in group {
+                    std::fs::remove_file(run)?;
+                }
+                merged_runs.push(path);
+            }
+            runs = merged_runs;
+        }
+
+        Ok(SortedRuns {
+            runs,
+            _dir: self.dir,
+        })
+    }
+}
+
+fn write_run(
+    path: &Path,
+    values: impl Iterator<Item = std::io::Result<u64>>,
+) -> std::io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    for value in values {
+        out.write_all(&value?.to_le_bytes())?;
+    }
+    out.flush()
+}
+
+/// The sorted runs of an [`ExternalSorter`], which can be read in order as often as needed.
+#[derive(Debug)]
+pub struct SortedRuns {
+    runs: Vec<PathBuf>,
+    _dir: SpillDir,
+}
+
+impl SortedRuns {
+    /// All numbers in ascending order.
+    pub fn iter(&self) -> std::io::Result<Merge> {
+        Merge::open(&self.runs)
+    }
+}
+
+/// K-way merge of sorted runs.
+pub struct Merge {
+    readers: Vec<BufReader<File>>,
+    /// Next number of every run that isn't exhausted yet, smallest on top
+    heads: BinaryHeap<Reverse<(u64, usize)>>,
+}
+
+impl Merge {
+    fn open(runs: &[PathBuf]) -> std::io::Result<Self> {
+        let mut merge = Merge {
+            readers: runs
+                .iter()
+                .map(|run| File::open(run).map(BufReader::new))
+                .collect::<Result<_, _>>()?,
+            heads: BinaryHeap::with_capacity(runs.len()),
+        };
+        for run_idx in 0..runs.len() {
+            merge.advance(run_idx)?;
+        }
+        Ok(merge)
+    }
+
+    /// Reads the next number of a run into `heads`.
+    fn advance(&mut self, run_idx: usize) -> std::io::Result<()> {
+        let mut bytes = [0; 8];
+        match self.readers[run_idx].read_exact(&mut bytes) {
+            Ok(()) => {
+                self.heads
+                    .push(Reverse((u64::from_le_bytes(bytes), run_idx)));
+                Ok(())
+            }
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Iterator for Merge {
+    type Item = std::io::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((value, run_idx)) = self.heads.pop()?;
+        Some(self.advance(run_idx).map(|()| value))
+    }
+}
+
+/// Error of solving from an input that doesn't fit into memory.
+#[derive(Debug)]
+pub enum ExternalError {
+    Parse(ParseError),
+    /// Spilling to or reading back from the temporary files failed
+    Spill(std::io::Error),
+}
+
+impl From<ParseError> for ExternalError {
+    fn from(err: ParseError) -> Self {
+        ExternalError::Parse(err)
+    }
+}
+
+impl From<std::io::Error> for ExternalError {
+    fn from(err: std::io::Error) -> Self {
+        ExternalError::Spill(err)
+    }
+}
+
+impl Display for ExternalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExternalError::Parse(err) => write!(f, "{err}"),
+            ExternalError::Spill(err) => write!(f, "failed to spill to temporary files: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ExternalError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    #[test]
+    fn sorts_in_runs() {
+        let mut rng = Rng::new(3);
+        let values = (0..1000).map(|_| rng.below(100)).collect::<Vec<_>>();
+
+        let mut sorter = ExternalSorter::new(&std::env::temp_dir(), 7)
+            .unwrap()
+            .with_fan_in(3);
+        for &value in &values {
+            sorter.push(value).unwrap();
+        }
+        let dir = sorter.dir.path.clone();
+        let runs = sorter.finish().unwrap();
+        assert!(runs.runs.len() <= 3);
+
+        let mut expected = values;
+        expected.sort();
+        for _ in 0..2 {
+            let sorted = runs.iter().unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(sorted, expected);
+        }
+
+        drop(runs);
+        assert!(!dir.exists());
+    }
+}
@@ -0,0 +1,623 @@
+use crate::days::{day06, day14};
+use crate::rng::Rng;
+use crate::simulation::Driver;
+use crate::{Dir4, Dir8, Grid, Part, Solution, Vec2};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// A generated puzzle input together with the answers that are known by construction. Answers
+/// are only planted where that is possible without solving the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub answers: Vec<(Part, u64)>,
+}
+
+impl Generated {
+    fn unplanted(input: String) -> Self {
+        Generated {
+            input,
+            answers: vec![],
+        }
+    }
+
+    pub fn answer(&self, part: Part) -> Option<u64> {
+        self.answers
+            .iter()
+            .find(|(answer_part, _)| *answer_part == part)
+            .map(|&(_, answer)| answer)
+    }
+}
+
+/// Days that have a generator.
+pub const GENERATORS: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+/// What `size` means for the generator of `day` and its default, which is about the size of the
+/// real puzzle inputs.
+pub fn default_size(day: u8) -> Option<(usize, &'static str)> {
+    let size = match day {
+        1 => (1000, "lines"),
+        2 => (1000, "reports"),
+        3 => (6, "lines"),
+        4 => (140, "grid side"),
+        5 => (200, "updates"),
+        6 => (130, "grid side"),
+        7 => (850, "equations"),
+        8 => (50, "grid side"),
+        9 => (10000, "files"),
+        10 => (50, "grid side"),
+        11 => (8, "stones"),
+        12 => (140, "grid side"),
+        13 => (320, "machines"),
+        14 => (500, "robots"),
+        15 => (50, "grid side"),
+        _ => return None,
+    };
+    Some(size)
+}
+
+/// Generates a random but valid input for `day`. The same seed and size always produce the same
+/// input. Returns `None` if there is no generator for that day.
+pub fn generate(day: u8, seed: u64, size: Option<usize>) -> Option<Generated> {
+    let size = size.unwrap_or(default_size(day)?.0);
+    let rng = &mut Rng::new(seed);
+    let generated = match day {
+        1 => day01(rng, size),
+        2 => day02(rng, size),
+        3 => day03(rng, size),
+        4 => {
+            let side = size.max(4);
+            day04(rng, side, side * side / 30)
+        }
+        5 => day05(rng, size),
+        6 => day06(rng, size.max(2)),
+        7 => day07(rng, size),
+        8 => day08(rng, size.max(1)),
+        9 => day09(rng, size),
+        10 => day10(rng, size.max(1)),
+        11 => day11(rng, size.max(1)),
+        12 => day12(rng, size.max(1)),
+        13 => day13(rng, size),
+        // Fewer robots can't form a tree that stands out
+        14 => day14(rng, size.max(100)),
+        15 => day15(rng, size.max(3)),
+        _ => return None,
+    };
+    Some(generated)
+}
+
+/// Plants the total distance: both lists are generated sorted, so the pairs are known before
+/// they are shuffled.
+fn day01(rng: &mut Rng, lines: usize) -> Generated {
+    let mut left = (0..lines)
+        .map(|_| rng.range(10000..=99999))
+        .sorted()
+        .collect::<Vec<_>>();
+    let mut right = (0..lines)
+        .map(|_| rng.range(10000..=99999))
+        .sorted()
+        .collect::<Vec<_>>();
+    let distance = left.iter().zip(&right).map(|(l, r)| l.abs_diff(*r)).sum();
+
+    rng.shuffle(&mut left);
+    rng.shuffle(&mut right);
+    let input = left
+        .iter()
+        .zip(&right)
+        .map(|(l, r)| format!("{l}   {r}\n"))
+        .collect();
+    Generated {
+        input,
+        answers: vec![(Part::A, distance)],
+    }
+}
+
+/// Plants the number of safe reports: unsafe reports get at least one step that is never
+/// allowed, no matter the direction.
+fn day02(rng: &mut Rng, reports: usize) -> Generated {
+    let mut safe = 0;
+    let mut input = String::new();
+    for _ in 0..reports {
+        let len = rng.range(5..=8) as usize;
+        let direction = *rng.choose(&[-1, 1]);
+        let mut steps = (1..len)
+            .map(|_| direction * rng.range(1..=3))
+            .collect::<Vec<_>>();
+        if rng.chance(50) {
+            let bad_idx = rng.index(steps.len());
+            steps[bad_idx] = direction * *rng.choose(&[0, 4, 5, 6, 7]);
+        } else {
+            safe += 1;
+        }
+
+        let mut levels = vec![rng.range(50..=99)];
+        for step in steps {
+            levels.push(levels[levels.len() - 1] + step);
+        }
+        writeln!(input, "{}", levels.iter().join(" ")).unwrap();
+    }
+
+    Generated {
+        input,
+        answers: vec![(Part::A, safe)],
+    }
+}
+
+/// Plants both sums. The filler never contains the letters instructions start with, so only the
+/// planted instructions and decoys can match.
+fn day03(rng: &mut Rng, lines: usize) -> Generated {
+    const FILLER: &[u8] = b"!@#$%^&*[]{}<>?+-_ 'xyzwhtne";
+    const DECOYS: &[&str] = &[
+        "mul[3,7]",
+        "mul(4*",
+        "mul ( 2 , 4 )",
+        "mul(32,64]",
+        "mul(1234,5)",
+        "don_t()",
+        "do ()",
+    ];
+
+    let mut input = String::new();
+    let mut total = 0;
+    let mut enabled_total = 0;
+    let mut enabled = true;
+    for _ in 0..lines {
+        for _ in 0..rng.range(100..=200) {
+            for _ in 0..rng.range(0..=8) {
+                input.push(*rng.choose(FILLER) as char);
+            }
+            match rng.below(10) {
+                0 => {
+                    input.push_str("do()");
+                    enabled = true;
+                }
+                1 => {
+                    input.push_str("don't()");
+                    enabled = false;
+                }
+                2 => input.push_str(rng.choose::<&str>(DECOYS)),
+                _ => {
+                    let a = rng.range(1..=999) as u64;
+                    let b = rng.range(1..=999) as u64;
+                    write!(input, "mul({a},{b})").unwrap();
+                    total += a * b;
+                    if enabled {
+                        enabled_total += a * b;
+                    }
+                }
+            }
+        }
+        input.push('\n');
+    }
+
+    Generated {
+        input,
+        answers: vec![(Part::A, total), (Part::B, enabled_total)],
+    }
+}
+
+/// Number of times `word` can be read in any of the eight directions, using at least one of the
+/// cells at `positions`.
+fn count_word_through(grid: &Grid<char>, word: &str, positions: &[Vec2]) -> usize {
+    let len = word.chars().count() as i64;
+    positions
+        .iter()
+        .cartesian_product(Dir8::ALL)
+        .flat_map(|(&pos, dir)| (0..len).map(move |offset| (pos - dir.to_vec2() * offset, dir)))
+        .unique()
+        .filter(|&(start, dir)| {
+            word.chars()
+                .zip(0..)
+                .all(|(c, idx)| grid.get(start + dir.to_vec2() * idx) == Some(&c))
+        })
+        .count()
+}
+
+/// Plants `count` XMAS in a grid of letters that don't occur in XMAS. Placements that would
+/// form additional XMAS together with earlier ones are undone.
+fn day04(rng: &mut Rng, side: usize, count: usize) -> Generated {
+    const FILLER: &[u8] = b"BCDEFGHIJKLNOPQRTUVWYZ";
+
+    let cells = (0..side * side)
+        .map(|_| *rng.choose(FILLER) as char)
+        .collect();
+    let mut grid = Grid::from_cells(side, cells);
+    let mut planted = 0;
+    while planted < count {
+        let start = Vec2::new(rng.index(side) as i64, rng.index(side) as i64);
+        let dir = *rng.choose(&Dir8::ALL);
+        let positions = (0..4)
+            .map(|idx| start + dir.to_vec2() * idx)
+            .collect::<Vec<_>>();
+        let fits = positions
+            .iter()
+            .all(|&pos| grid.get(pos).is_some_and(|c| !"XMAS".contains(*c)));
+        if !fits {
+            continue;
+        }
+
+        let previous = positions.iter().map(|&pos| grid[pos]).collect::<Vec<_>>();
+        for (&pos, c) in positions.iter().zip("XMAS".chars()) {
+            grid[pos] = c;
+        }
+        if count_word_through(&grid, "XMAS", &positions) == 1 {
+            planted += 1;
+        } else {
+            for (&pos, c) in positions.iter().zip(previous) {
+                grid[pos] = c;
+            }
+        }
+    }
+
+    Generated {
+        input: grid.to_string(),
+        answers: vec![(Part::A, count as u64)],
+    }
+}
+
+/// Most XMAS that can be planted into a word search with sides of length `side`. Beyond that,
+/// there isn't reliably room left for another one.
+pub fn max_xmas(side: usize) -> usize {
+    side * side / 20
+}
+
+/// Generates a day 4 word search with sides of length `side` that contains exactly `count` XMAS.
+/// Returns `None` if more than [`max_xmas`] are requested.
+pub fn word_search(seed: u64, side: usize, count: usize) -> Option<Generated> {
+    let side = side.max(4);
+    (count <= max_xmas(side)).then(|| day04(&mut Rng::new(seed), side, count))
+}
+
+/// Plants both sums: there is a rule for every pair of pages, taken from a random total order, so
+/// the correct order of every update is known.
+fn day05(rng: &mut Rng, updates: usize) -> Generated {
+    let mut pages = (10..=99).collect::<Vec<u64>>();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+    let rank = pages
+        .iter()
+        .enumerate()
+        .map(|(rank, &page)| (page, rank))
+        .collect::<HashMap<_, _>>();
+
+    let mut rules = pages
+        .iter()
+        .tuple_combinations::<(_, _)>()
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut rules);
+    let mut input = rules
+        .iter()
+        .map(|(before, after)| format!("{before}|{after}\n"))
+        .collect::<String>();
+    input.push('\n');
+
+    let (mut ordered_sum, mut reordered_sum) = (0, 0);
+    for _ in 0..updates {
+        let len = rng.range(2..=11) as usize * 2 + 1;
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(len);
+        let mut sorted = update.clone();
+        sorted.sort_by_key(|page| rank[page]);
+        if rng.chance(50) {
+            update = sorted.clone();
+        }
+
+        if update == sorted {
+            ordered_sum += sorted[len / 2];
+        } else {
+            reordered_sum += sorted[len / 2];
+        }
+        writeln!(input, "{}", update.iter().join(",")).unwrap();
+    }
+
+    Generated {
+        input,
+        answers: vec![(Part::A, ordered_sum), (Part::B, reordered_sum)],
+    }
+}
+
+/// A lab the guard leaves without getting stuck, obstacles are redrawn until that is the case.
+fn day06(rng: &mut Rng, side: usize) -> Generated {
+    loop {
+        let mut grid = Grid::new(side, side, '.');
+        for pos in grid.positions().collect::<Vec<_>>() {
+            if rng.chance(8) {
+                grid[pos] = '#';
+            }
+        }
+        let guard = Vec2::new(rng.index(side) as i64, rng.index(side) as i64);
+        grid[guard] = '^';
+
+        let input = grid.to_string();
+        let lab = day06::Day06::parse(&input).expect("generated lab is valid");
+        let mut patrol = day06::Patrol::new(&lab);
+        Driver::new().run(&mut patrol);
+        if !patrol.is_looping() {
+            return Generated::unplanted(input);
+        }
+    }
+}
+
+/// Half of the test values can be produced with the operators, the rest likely can't. At most
+/// six operands of up to three digits keep every intermediate result within `u64`.
+fn day07(rng: &mut Rng, equations: usize) -> Generated {
+    let mut input = String::new();
+    for _ in 0..equations {
+        let operands = (0..rng.range(2..=6))
+            .map(|_| rng.range(1..=999) as u64)
+            .collect::<Vec<_>>();
+        let mut value =
+            operands[1..]
+                .iter()
+                .fold(operands[0], |acc, &operand| match rng.below(3) {
+                    0 => acc + operand,
+                    1 => acc * operand,
+                    _ => format!("{acc}{operand}").parse().expect("fits into u64"),
+                });
+        if rng.chance(50) {
+            value += rng.range(1..=9) as u64;
+        }
+        writeln!(input, "{value}: {}", operands.iter().join(" ")).unwrap();
+    }
+    Generated::unplanted(input)
+}
+
+fn day08(rng: &mut Rng, side: usize) -> Generated {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    let mut grid = Grid::new(side, side, '.');
+    for _ in 0..side / 2 {
+        let frequency = *rng.choose(FREQUENCIES) as char;
+        for _ in 0..rng.range(2..=5) {
+            let pos = Vec2::new(rng.index(side) as i64, rng.index(side) as i64);
+            grid[pos] = frequency;
+        }
+    }
+    Generated::unplanted(grid.to_string())
+}
+
+fn day09(rng: &mut Rng, files: usize) -> Generated {
+    let mut input = (0..files)
+        .map(|idx| {
+            let file = char::from(b'0' + rng.range(1..=9) as u8);
+            if idx + 1 == files {
+                file.to_string()
+            } else {
+                format!("{file}{}", rng.range(0..=9))
+            }
+        })
+        .collect::<String>();
+    input.push('\n');
+    Generated::unplanted(input)
+}
+
+/// Heights are a random walk from the neighbours above and to the left, so there are trails.
+fn day10(rng: &mut Rng, side: usize) -> Generated {
+    let mut grid = Grid::new(side, side, 0i64);
+    for pos in grid.positions().collect::<Vec<_>>() {
+        let neighbor = [Dir4::North, Dir4::West]
+            .into_iter()
+            .filter_map(|dir| grid.get(pos + dir.to_vec2()).copied())
+            .collect::<Vec<_>>();
+        grid[pos] = match neighbor.as_slice() {
+            [] => rng.range(0..=9),
+            heights => (*rng.choose(heights) + rng.range(-1..=1)).clamp(0, 9),
+        };
+    }
+    Generated::unplanted(grid.to_string())
+}
+
+/// Number of stones `stone` turns into after `blinks` blinks, counted by plain recursion so it
+/// doesn't share any code with the solution.
+fn count_stones(stone: u64, blinks: usize, memo: &mut HashMap<(u64, usize), u64>) -> u64 {
+    if blinks == 0 {
+        return 1;
+    }
+    if let Some(&count) = memo.get(&(stone, blinks)) {
+        return count;
+    }
+
+    let digits = stone.to_string();
+    let count = if stone == 0 {
+        count_stones(1, blinks - 1, memo)
+    } else if digits.len().is_multiple_of(2) {
+        let (left, right) = digits.split_at(digits.len() / 2);
+        count_stones(left.parse().unwrap(), blinks - 1, memo)
+            + count_stones(right.parse().unwrap(), blinks - 1, memo)
+    } else {
+        count_stones(stone * 2024, blinks - 1, memo)
+    };
+    memo.insert((stone, blinks), count);
+    count
+}
+
+/// Plants both stone counts, precomputed with [`count_stones`].
+fn day11(rng: &mut Rng, stones: usize) -> Generated {
+    let stones = (0..stones)
+        .map(|_| rng.range(0..=999_999) as u64)
+        .collect::<Vec<_>>();
+    let memo = &mut HashMap::new();
+    let mut count = |blinks| {
+        stones
+            .iter()
+            .map(|&stone| count_stones(stone, blinks, memo))
+            .sum()
+    };
+    let answers = vec![(Part::A, count(25)), (Part::B, count(75))];
+
+    Generated {
+        input: format!("{}\n", stones.iter().join(" ")),
+        answers,
+    }
+}
+
+/// Plots mostly continue the region above or to the left, so there are regions of all shapes.
+fn day12(rng: &mut Rng, side: usize) -> Generated {
+    let mut grid = Grid::new(side, side, 'A');
+    for pos in grid.positions().collect::<Vec<_>>() {
+        let neighbors = [Dir4::North, Dir4::West]
+            .into_iter()
+            .filter_map(|dir| grid.get(pos + dir.to_vec2()).copied())
+            .collect::<Vec<_>>();
+        grid[pos] = if !neighbors.is_empty() && rng.chance(80) {
+            *rng.choose(&neighbors)
+        } else {
+            char::from(b'A' + rng.below(26) as u8)
+        };
+    }
+    Generated::unplanted(grid.to_string())
+}
+
+/// Plants the tokens of part a. The button moves are linearly independent, so every machine has
+/// at most one way to win. Unwinnable machines are off by one from a winnable prize, which needs
+/// a fraction of a press because the determinant is larger than any move.
+fn day13(rng: &mut Rng, machines: usize) -> Generated {
+    let mut input = String::new();
+    let mut tokens = 0;
+    for idx in 0..machines {
+        let (a, b) = loop {
+            let a = Vec2::new(rng.range(10..=99), rng.range(10..=99));
+            let b = Vec2::new(rng.range(10..=99), rng.range(10..=99));
+            if (a.x * b.y - a.y * b.x).abs() > 99 {
+                break (a, b);
+            }
+        };
+        let (a_presses, b_presses) = (rng.range(0..=100), rng.range(0..=100));
+        let mut prize = a * a_presses + b * b_presses;
+        if rng.chance(25) {
+            prize.x += 1;
+        } else {
+            tokens += (3 * a_presses + b_presses) as u64;
+        }
+
+        if idx > 0 {
+            input.push('\n');
+        }
+        write!(
+            input,
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.x, a.y, b.x, b.y, prize.x, prize.y
+        )
+        .unwrap();
+    }
+
+    Generated {
+        input,
+        answers: vec![(Part::A, tokens)],
+    }
+}
+
+/// Plants the second of the christmas tree: most robots are placed on a filled triangle at a
+/// random second and moved back to where they start.
+fn day14(rng: &mut Rng, robots: usize) -> Generated {
+    let bounds = day14::BOUNDS;
+    let tree_at = rng.range(1000..=bounds.x * bounds.y - 1);
+
+    let tree_rows = ((robots * 3 / 5) as f64).sqrt() as i64;
+    let top = Vec2::new(rng.range(tree_rows..=bounds.x - tree_rows - 1), 0);
+    let top = top + Vec2::new(0, rng.range(0..=bounds.y - tree_rows - 1));
+    let mut positions = (0..tree_rows)
+        .flat_map(|row| (-row..=row).map(move |x| top + Vec2::new(x, row)))
+        .collect::<Vec<_>>();
+    while positions.len() < robots {
+        positions.push(Vec2::new(
+            rng.range(0..=bounds.x - 1),
+            rng.range(0..=bounds.y - 1),
+        ));
+    }
+    rng.shuffle(&mut positions);
+
+    let mut input = String::new();
+    for position in positions.into_iter().take(robots) {
+        let velocity = Vec2::new(rng.range(-99..=99), rng.range(-99..=99));
+        let start = position.wrapping_add(velocity.wrapping_mul(-tree_at, bounds), bounds);
+        writeln!(
+            input,
+            "p={},{} v={},{}",
+            start.x, start.y, velocity.x, velocity.y
+        )
+        .unwrap();
+    }
+
+    Generated {
+        input,
+        answers: vec![(Part::B, tree_at as u64)],
+    }
+}
+
+fn day15(rng: &mut Rng, side: usize) -> Generated {
+    let mut grid = Grid::new(side, side, '.');
+    for pos in grid.positions().collect::<Vec<_>>() {
+        let border =
+            pos.x == 0 || pos.y == 0 || pos.x == side as i64 - 1 || pos.y == side as i64 - 1;
+        grid[pos] = if border || rng.chance(5) {
+            '#'
+        } else if rng.chance(25) {
+            'O'
+        } else {
+            '.'
+        };
+    }
+    let robot = Vec2::new(
+        rng.range(1..=side as i64 - 2),
+        rng.range(1..=side as i64 - 2),
+    );
+    grid[robot] = '@';
+
+    let mut input = grid.to_string();
+    input.push('\n');
+    for _ in 0..side / 2 {
+        let moves = (0..70)
+            .map(|_| rng.choose(&Dir4::ALL).arrow())
+            .collect::<String>();
+        writeln!(input, "{moves}").unwrap();
+    }
+    Generated::unplanted(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn generates_deterministically() {
+        for &day in GENERATORS {
+            let generated = generate(day, 42, Some(12)).unwrap();
+            assert_eq!(generate(day, 42, Some(12)), Some(generated.clone()));
+            assert_ne!(generate(day, 43, Some(12)), Some(generated));
+        }
+        assert!(generate(0, 42, None).is_none());
+    }
+
+    #[test]
+    fn solutions_find_planted_answers() {
+        for &day in GENERATORS {
+            for seed in 0..2 {
+                let generated = generate(day, seed, Some(10)).unwrap();
+                let result = days::run(day, &generated.input, &Part::ALL)
+                    .unwrap()
+                    .unwrap_or_else(|e| panic!("day {day}, seed {seed}: {e}"));
+                for &(part, answer) in &generated.answers {
+                    assert_eq!(
+                        result.answer(part),
+                        Some(answer),
+                        "day {day}, seed {seed}, part {part}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn plants_chosen_number_of_xmas() {
+        for count in [0, 1, 7, max_xmas(12)] {
+            let generated = word_search(count as u64, 12, count).unwrap();
+            let result = days::run(4, &generated.input, &[Part::A]).unwrap().unwrap();
+            assert_eq!(result.answer(Part::A), Some(count as u64));
+        }
+        assert!(word_search(0, 12, max_xmas(12) + 1).is_none());
+    }
+}
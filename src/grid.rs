@@ -0,0 +1,309 @@
+use crate::{Dir4, Dir8, InputLine, ParseError, ParseErrorKind, Vec2};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from cells in row-major order. Panics if the number of cells isn't a
+    /// multiple of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "cells don't form rows"
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Parses a rectangular grid with one character per cell from the given lines. `parse_cell`
+    /// is called with the position and character of every cell and returns `None` for
+    /// characters that aren't valid, which are then reported as `expected`.
+    pub fn from_lines<'a>(
+        lines: impl Iterator<Item = InputLine<'a>>,
+        expected: &'static str,
+        mut parse_cell: impl FnMut(Vec2, char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut last_line = 0;
+        let mut cells = Vec::new();
+
+        for (y, line) in lines.enumerate() {
+            last_line = line.number;
+            line.expect_row_len(*width.get_or_insert(line.text.chars().count()))?;
+            for (x, c) in line.text.chars().enumerate() {
+                let cell = parse_cell(Vec2::new(x as i64, y as i64), c).ok_or_else(|| {
+                    line.char_error(x, c, ParseErrorKind::UnexpectedToken(expected))
+                })?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::missing_element(last_line, "grid")),
+        }
+    }
+
+    /// Parses a whole input as grid, see [`Grid::from_lines`].
+    pub fn parse_with(
+        input: &str,
+        expected: &'static str,
+        parse_cell: impl FnMut(Vec2, char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        Self::from_lines(InputLine::enumerate(input), expected, parse_cell)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Width and height of the grid as exclusive upper bound of all positions.
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(self.width as i64, self.height as i64)
+    }
+
+    pub fn contains(&self, pos: Vec2) -> bool {
+        pos.is_within_bounds(self.size())
+    }
+
+    /// Index of the given position in row-major order, `None` if it is outside the grid.
+    pub fn index_of(&self, pos: Vec2) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    /// Position of the given row-major index.
+    pub fn pos_of(&self, index: usize) -> Vec2 {
+        Vec2::new((index % self.width) as i64, (index / self.width) as i64)
+    }
+
+    pub fn get(&self, pos: Vec2) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Vec2) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// All positions of the grid in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| Vec2::new((idx % width) as i64, (idx / width) as i64))
+    }
+
+    /// All cells together with their position in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The up to four orthogonally adjacent positions that are inside the grid.
+    pub fn neighbors4(&self, pos: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        Dir4::ALL
+            .iter()
+            .map(move |dir| pos + dir.to_vec2())
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// The up to eight orthogonally or diagonally adjacent positions that are inside the grid.
+    pub fn neighbors8(&self, pos: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        Dir8::ALL
+            .iter()
+            .map(move |dir| pos + dir.to_vec2())
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// Position of the first cell in row-major order matching the predicate.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Vec2> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|idx| self.pos_of(idx))
+    }
+
+    /// Positions of all cells equal to `value` in row-major order.
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Vec2> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Walks from `start` in steps of `step` until leaving the grid, starting with `start` itself.
+    pub fn ray(&self, start: Vec2, step: Vec2) -> impl Iterator<Item = (Vec2, &T)> + '_ {
+        (0..)
+            .map(move |dist| start + step * dist)
+            .map_while(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Diagonals running down and to the right, starting with the one in the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let left_column = (0..self.height as i64).rev().map(|y| Vec2::new(0, y));
+        let top_row = (1..self.width as i64).map(|x| Vec2::new(x, 0));
+
+        left_column.chain(top_row).map(|start| {
+            self.ray(start, Dir8::SouthEast.to_vec2())
+                .map(|(_, cell)| cell)
+        })
+    }
+
+    /// Diagonals running down and to the left, starting with the one in the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let top_row = (0..self.width as i64).map(|x| Vec2::new(x, 0));
+        let right_column = (1..self.height as i64).map(|y| Vec2::new(self.width as i64 - 1, y));
+
+        top_row.chain(right_column).map(|start| {
+            self.ray(start, Dir8::SouthWest.to_vec2())
+                .map(|(_, cell)| cell)
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of arbitrary characters, see [`Grid::from_lines`].
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, "any character", |_, c| Some(c))
+    }
+}
+
+impl<T> Index<Vec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Vec2> for Grid<T> {
+    fn index_mut(&mut self, pos: Vec2) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "abc\ndef\n";
+
+    #[test]
+    fn parse_and_index() {
+        let grid = Grid::parse_chars(SAMPLE).unwrap();
+        assert_eq!(grid.size(), Vec2::new(3, 2));
+        assert_eq!(grid[Vec2::new(2, 1)], 'f');
+        assert_eq!(grid.get(Vec2::new(3, 0)), None);
+        assert_eq!(grid.get(Vec2::new(0, -1)), None);
+        assert_eq!(grid.find(|&c| c == 'e'), Some(Vec2::new(1, 1)));
+        assert_eq!(grid.to_string(), SAMPLE);
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let err = Grid::parse_chars("abc\nde\n").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.kind, ParseErrorKind::RaggedRow { expected_len: 3 });
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = Grid::parse_chars(SAMPLE).unwrap();
+        assert_eq!(grid.neighbors4(Vec2::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbors8(Vec2::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8(Vec2::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn lines_through_grid() {
+        let grid = Grid::parse_chars(SAMPLE).unwrap();
+        let collect = |line: &mut dyn Iterator<Item = &char>| line.collect::<String>();
+
+        assert_eq!(
+            grid.columns()
+                .map(|mut c| collect(&mut c))
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            ["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            ["a", "bd", "ce", "f"]
+        );
+    }
+}
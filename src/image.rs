@@ -0,0 +1,272 @@
+use crate::simulation::Recording;
+use crate::Grid;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    /// Brightness of the color, using the Rec. 601 luma weights.
+    pub fn gray(self) -> u8 {
+        let Rgb(r, g, b) = self;
+        ((299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000) as u8
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = String;
+
+    /// Parses six hex digits like `ff8000`, optionally prefixed with `#`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        let channel = |idx: usize| {
+            hex.get(idx..idx + 2)
+                .filter(|_| hex.len() == 6)
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .ok_or_else(|| format!("invalid color '{s}', expected 'rrggbb'"))
+        };
+        Ok(Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+/// Colors of the cells of rendered grids, by the character that represents them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<(char, Rgb)>,
+    fallback: Rgb,
+}
+
+impl Palette {
+    /// A palette without any colors, every cell is drawn in `fallback`.
+    pub fn new(fallback: Rgb) -> Self {
+        Palette {
+            colors: vec![],
+            fallback,
+        }
+    }
+
+    /// Draws cells shown as `cell` in `color`, replacing any previous color of `cell`.
+    pub fn with(mut self, cell: char, color: Rgb) -> Self {
+        self.colors.retain(|&(c, _)| c != cell);
+        self.colors.push((cell, color));
+        self
+    }
+
+    pub fn color(&self, cell: char) -> Rgb {
+        self.colors
+            .iter()
+            .find(|&&(c, _)| c == cell)
+            .map_or(self.fallback, |&(_, color)| color)
+    }
+
+    /// Overrides colors with a comma-separated list of `<cell>=<rrggbb>` entries.
+    pub fn with_spec(self, spec: &str) -> Result<Self, String> {
+        spec.split(',')
+            .filter(|entry| !entry.is_empty())
+            .try_fold(self, |palette, entry| {
+                let mut chars = entry.chars();
+                let (Some(cell), Some('=')) = (chars.next(), chars.next()) else {
+                    return Err(format!(
+                        "invalid palette entry '{entry}', expected '<cell>=<rrggbb>'"
+                    ));
+                };
+                Ok(palette.with(cell, chars.as_str().parse()?))
+            })
+    }
+}
+
+impl Default for Palette {
+    /// Colors for the cells drawn by the simulations of days 6, 14 and 15.
+    fn default() -> Self {
+        let palette = Palette::new(Rgb::WHITE)
+            .with('.', Rgb::BLACK)
+            .with('#', Rgb(128, 128, 128))
+            .with('X', Rgb(200, 40, 40))
+            .with('O', Rgb(180, 120, 60))
+            .with('[', Rgb(180, 120, 60))
+            .with(']', Rgb(180, 120, 60))
+            .with('+', Rgb(0, 255, 0));
+        let robots = ['@', '^', '>', 'v', '<'];
+        let palette = robots
+            .into_iter()
+            .fold(palette, |palette, c| palette.with(c, Rgb(255, 220, 0)));
+        // More robots on a tile make it brighter
+        ('1'..='9').zip(1u8..).fold(palette, |palette, (c, count)| {
+            palette.with(c, Rgb(0, 75 + 20 * count, 0))
+        })
+    }
+}
+
+/// The binary variants of the netpbm formats, which any image viewer can open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Color image
+    Ppm,
+    /// Grayscale image
+    Pgm,
+}
+
+impl ImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Pgm => "pgm",
+        }
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ppm" => Ok(ImageFormat::Ppm),
+            "pgm" => Ok(ImageFormat::Pgm),
+            _ => Err(format!(
+                "invalid image format '{s}', expected 'ppm' or 'pgm'"
+            )),
+        }
+    }
+}
+
+/// How grids are turned into images.
+#[derive(Debug, Clone)]
+pub struct ImageOptions {
+    pub format: ImageFormat,
+    pub palette: Palette,
+    /// Width and height of a cell in pixels
+    pub scale: usize,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        ImageOptions {
+            format: ImageFormat::Ppm,
+            palette: Palette::default(),
+            scale: 1,
+        }
+    }
+}
+
+/// Writes `grid` as image, every cell is a square of `options.scale` pixels.
+pub fn write_image(
+    grid: &Grid<char>,
+    options: &ImageOptions,
+    out: impl Write,
+) -> std::io::Result<()> {
+    let mut out = BufWriter::new(out);
+    let magic = match options.format {
+        ImageFormat::Ppm => "P6",
+        ImageFormat::Pgm => "P5",
+    };
+    let scale = options.scale;
+    writeln!(
+        out,
+        "{magic}\n{} {}\n255",
+        grid.width() * scale,
+        grid.height() * scale
+    )?;
+
+    for row in grid.rows() {
+        let mut pixels = Vec::new();
+        for &cell in row {
+            let color = options.palette.color(cell);
+            for _ in 0..scale {
+                match options.format {
+                    ImageFormat::Ppm => pixels.extend([color.0, color.1, color.2]),
+                    ImageFormat::Pgm => pixels.push(color.gray()),
+                }
+            }
+        }
+        for _ in 0..scale {
+            out.write_all(&pixels)?;
+        }
+    }
+    out.flush()
+}
+
+/// Path of the image of the frame at `step`, like `frames/day06-000042.ppm` for the prefix
+/// `day06-`.
+pub fn frame_path(dir: &Path, prefix: &str, step: usize, format: ImageFormat) -> PathBuf {
+    dir.join(format!("{prefix}{step:06}.{}", format.extension()))
+}
+
+/// Writes every recorded frame as an image numbered by its step into `dir`, which is created if
+/// necessary. Returns the paths of the written images in order.
+pub fn write_frames(
+    recording: &Recording,
+    dir: &Path,
+    prefix: &str,
+    options: &ImageOptions,
+) -> std::io::Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir)?;
+    recording
+        .frames()
+        .iter()
+        .map(|frame| {
+            let path = frame_path(dir, prefix, frame.step, options.format);
+            write_image(&frame.grid, options, std::fs::File::create(&path)?)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_scaled_images() {
+        let grid = Grid::parse_chars(".#\n").unwrap();
+        let palette = Palette::new(Rgb::WHITE).with('#', "#ff8000".parse().unwrap());
+
+        let mut ppm = Vec::new();
+        let options = ImageOptions {
+            format: ImageFormat::Ppm,
+            palette,
+            scale: 2,
+        };
+        write_image(&grid, &options, &mut ppm).unwrap();
+        let (header, pixels) = ppm.split_at(b"P6\n4 2\n255\n".len());
+        assert_eq!(header, b"P6\n4 2\n255\n");
+        assert_eq!(pixels.len(), 4 * 2 * 3);
+        assert_eq!(
+            &pixels[..12],
+            &[255, 255, 255, 255, 255, 255, 255, 128, 0, 255, 128, 0]
+        );
+
+        let mut pgm = Vec::new();
+        let options = ImageOptions {
+            format: ImageFormat::Pgm,
+            scale: 1,
+            ..options
+        };
+        write_image(&grid, &options, &mut pgm).unwrap();
+        assert_eq!(pgm, b"P5\n2 1\n255\n\xff\x97");
+    }
+
+    #[test]
+    fn parses_palette_spec() {
+        let palette = Palette::default().with_spec("#=000080,X=ffffff").unwrap();
+        assert_eq!(palette.color('#'), Rgb(0, 0, 128));
+        assert_eq!(palette.color('X'), Rgb::WHITE);
+        assert_eq!(palette.color('.'), Rgb::BLACK);
+
+        assert!(Palette::default().with_spec("#000080").is_err());
+        assert!(Palette::default().with_spec("#=0080").is_err());
+    }
+
+    #[test]
+    fn numbers_frames_by_step() {
+        let dir = Path::new("frames");
+        assert_eq!(
+            frame_path(dir, "day14-", 42, ImageFormat::Pgm),
+            Path::new("frames/day14-000042.pgm")
+        );
+    }
+}
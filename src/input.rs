@@ -0,0 +1,246 @@
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, BufReader, Lines, Read};
+use std::path::{Path, PathBuf};
+
+/// Directory, relative to the working directory, in which puzzle inputs are looked up.
+pub const INPUT_DIR: &str = "inputs";
+
+/// Where the puzzle input of a run comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Picks the input for `day`: the explicitly given path if there is one, otherwise
+    /// `inputs/dayNN.txt` if that file exists, otherwise stdin.
+    pub fn resolve(day: u8, explicit_path: Option<&Path>) -> Self {
+        if let Some(path) = explicit_path {
+            return InputSource::File(path.to_owned());
+        }
+
+        let default_path = default_input_path(day);
+        if default_path.is_file() {
+            InputSource::File(default_path)
+        } else {
+            InputSource::Stdin
+        }
+    }
+
+    /// Reads the whole input and normalizes it with [`sanitize`]. Lines may be arbitrarily long,
+    /// nothing is read line by line.
+    pub fn load(&self) -> Result<String, InputError> {
+        let mut input = String::new();
+        let res = match self {
+            InputSource::File(path) => {
+                std::fs::File::open(path).and_then(|mut file| file.read_to_string(&mut input))
+            }
+            InputSource::Stdin => std::io::stdin().lock().read_to_string(&mut input),
+        };
+
+        res.map(|_| sanitize(&input)).map_err(|err| InputError {
+            source: self.clone(),
+            err,
+        })
+    }
+
+    /// Opens the input to be read line by line, for inputs that are too large to [`load`] them.
+    /// Pass the reader to [`sanitized_lines`] to get the same lines as [`load`] would.
+    ///
+    /// [`load`]: InputSource::load
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            InputSource::File(path) => std::fs::File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|err| InputError {
+                    source: self.clone(),
+                    err,
+                }),
+            InputSource::Stdin => Ok(Box::new(std::io::stdin().lock())),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// Normalizes the quirks of saved puzzle inputs so parsers only ever see one format: a leading
+/// byte order mark is dropped, CRLF line endings become LF, trailing whitespace is stripped from
+/// every line and trailing blank lines are removed. A non-empty result ends with exactly one
+/// newline.
+pub fn sanitize(raw: &str) -> String {
+    let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    let lines = raw.lines().map(str::trim_end).collect::<Vec<_>>();
+    let len = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |idx| idx + 1);
+
+    let mut sanitized = String::with_capacity(raw.len());
+    for line in &lines[..len] {
+        sanitized.push_str(line);
+        sanitized.push('\n');
+    }
+    sanitized
+}
+
+/// The lines of [`sanitize`], read one at a time. Blank lines are held back until a non-blank line
+/// follows them, so trailing ones never show up.
+pub fn sanitized_lines<R: BufRead>(reader: R) -> SanitizedLines<R> {
+    SanitizedLines {
+        lines: reader.lines(),
+        first: true,
+        blank: 0,
+        pending: None,
+    }
+}
+
+pub struct SanitizedLines<R> {
+    lines: Lines<R>,
+    first: bool,
+    /// Blank lines read but not returned yet
+    blank: usize,
+    /// Non-blank line to return once the blank lines before it are
+    pending: Option<String>,
+}
+
+impl<R: BufRead> Iterator for SanitizedLines<R> {
+    type Item = std::io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.pending.is_some() {
+                if self.blank > 0 {
+                    self.blank -= 1;
+                    return Some(Ok(String::new()));
+                }
+                return self.pending.take().map(Ok);
+            }
+
+            let mut line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+            if std::mem::take(&mut self.first) && line.starts_with('\u{feff}') {
+                line.drain(..'\u{feff}'.len_utf8());
+            }
+            line.truncate(line.trim_end().len());
+
+            if line.is_empty() {
+                self.blank += 1;
+            } else {
+                self.pending = Some(line);
+            }
+        }
+    }
+}
+
+/// `inputs/dayNN.txt` with a zero-padded day.
+pub fn default_input_path(day: u8) -> PathBuf {
+    Path::new(INPUT_DIR).join(format!("day{day:02}.txt"))
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: InputSource,
+    pub err: std::io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to read input from {}: {}", self.source, self.err)
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_explicit_path_before_default() {
+        let explicit = Path::new("some/where.txt");
+        assert_eq!(
+            InputSource::resolve(9, Some(explicit)),
+            InputSource::File(explicit.to_owned())
+        );
+        assert_eq!(default_input_path(9), Path::new("inputs/day09.txt"));
+    }
+
+    #[test]
+    fn loads_long_lines() {
+        let path = std::env::temp_dir().join(format!("aoc24-input-test-{}", std::process::id()));
+        let line = "7".repeat(100_000);
+        std::fs::write(&path, format!("{line}\n")).unwrap();
+
+        let input = InputSource::File(path.clone()).load();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(input.unwrap().lines().next(), Some(line.as_str()));
+    }
+
+    #[test]
+    fn sanitizes_line_endings() {
+        let expected = "1   2\n\n3   4\n";
+        for raw in [
+            "1   2\n\n3   4",
+            "1   2\n\n3   4\n",
+            "1   2\n\n3   4\n\n\n",
+            "1   2\r\n\r\n3   4\r\n",
+            "1   2\r\n\r\n3   4\r\n\r\n",
+            "\u{feff}1   2\n\n3   4\n",
+            "1   2 \t\n  \n3   4  \r\n \n",
+        ] {
+            assert_eq!(sanitize(raw), expected, "raw input {raw:?}");
+        }
+        for raw in ["\u{feff}\r\n\n", "1   2\n\n\n3   4\r\n \n", "\n\n  x \n"] {
+            let streamed = sanitized_lines(raw.as_bytes())
+                .map(|line| line.unwrap() + "\n")
+                .collect::<String>();
+            assert_eq!(streamed, sanitize(raw), "raw input {raw:?}");
+        }
+        assert_eq!(sanitize(""), "");
+        assert_eq!(sanitize("\u{feff}\r\n\n"), "");
+        assert_eq!(sanitize("  ..#\n"), "  ..#\n");
+    }
+
+    #[test]
+    fn loaded_inputs_solve_regardless_of_line_endings() {
+        let example = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\n\
+                       Button A: X+26, Y+66\nButton B: X+67, Y+21\nPrize: X=12748, Y=12176\n";
+        let path = std::env::temp_dir().join(format!("aoc24-crlf-test-{}", std::process::id()));
+        let windows = format!("\u{feff}{}\r\n\r\n", example.replace('\n', "\r\n"));
+        std::fs::write(&path, windows).unwrap();
+
+        let input = InputSource::File(path.clone()).load();
+        std::fs::remove_file(&path).unwrap();
+        let input = input.unwrap();
+        assert_eq!(input, example);
+
+        let result = crate::days::run(13, &input, &crate::Part::ALL)
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.answer(crate::Part::A), Some(280));
+    }
+
+    #[test]
+    fn reports_missing_file() {
+        let err = InputSource::File("does/not/exist.txt".into())
+            .load()
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("failed to read input from does/not/exist.txt"));
+    }
+}
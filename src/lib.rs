@@ -1,37 +1,130 @@
-use std::io::{stdin, BufRead};
+use crate::settings::Settings;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
 
-pub fn parse_input_lists() -> (Vec<u64>, Vec<u64>) {
-    stdin()
-        .lock()
-        .lines()
-        .map(|line_res| {
-            let line = line_res.expect("stream error");
-            let mut parts = line.split_whitespace();
-            let first = parts
-                .next()
-                .expect("malformed input")
-                .parse::<u64>()
-                .expect("malformed input");
-            let second = parts
-                .next()
-                .expect("malformed input")
-                .parse::<u64>()
-                .expect("malformed input");
-
-            (first, second)
-        })
-        .unzip()
+pub mod animate;
+pub mod answers;
+pub mod batch;
+pub mod bench;
+pub mod columns;
+pub mod days;
+pub mod differential;
+pub mod dir;
+pub mod error;
+pub mod external;
+pub mod generate;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod json;
+pub mod metrics;
+pub mod rng;
+pub mod scaffold;
+pub mod settings;
+pub mod simulation;
+pub mod vec2;
+
+pub use dir::{Dir4, Dir8};
+pub use error::{InputLine, ParseError, ParseErrorKind};
+pub use grid::Grid;
+pub use input::InputSource;
+pub use vec2::Vec2;
+
+/// One of the two parts every puzzle day consists of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::A, Part::B];
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" | "1" => Ok(Part::A),
+            "b" | "B" | "2" => Ok(Part::B),
+            _ => Err(format!("invalid part '{s}', expected 'a' or 'b'")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::A => f.pad("a"),
+            Part::B => f.pad("b"),
+        }
+    }
+}
+
+/// A single puzzle day. The input is parsed once and shared by both parts.
+pub trait Solution {
+    /// Day of the advent calendar this solution belongs to.
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// The answer of part a, or `None` if the input has none, like day 14 without a christmas
+    /// tree.
+    fn part_a(input: &Self::Input) -> Option<u64>;
+
+    /// The answer of part b, or `None` if the input has none.
+    fn part_b(input: &Self::Input) -> Option<u64>;
+
+    fn solve(input: &Self::Input, part: Part) -> Option<u64> {
+        match part {
+            Part::A => Self::part_a(input),
+            Part::B => Self::part_b(input),
+        }
+    }
+
+    /// Solves a part with rules that may differ from the puzzle. Days without settings ignore
+    /// them, the others read them into their own options.
+    fn solve_with(input: &Self::Input, part: Part, _settings: &Settings) -> Option<u64> {
+        Self::solve(input, part)
+    }
 }
 
-pub fn parse_input_reports() -> Vec<Vec<u64>> {
-    stdin()
-        .lock()
+pub fn parse_input_reports(reader: impl BufRead) -> Result<Vec<Vec<u64>>, ParseError> {
+    reader
         .lines()
-        .map(|line_res| {
-            let line = line_res.expect("stream error");
-            line.split_whitespace()
-                .map(|num_str| num_str.parse().expect("not a number"))
+        .enumerate()
+        .map(|(idx, line_res)| {
+            let text = line_res.map_err(|e| ParseError::io(idx + 1, e))?;
+            let line = InputLine::new(idx + 1, &text);
+            text.split_whitespace()
+                .map(|num_str| line.parse_number(num_str))
                 .collect()
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_reports() {
+        let reports = parse_input_reports("7 6 4\n1 2\n\n".as_bytes()).unwrap();
+        assert_eq!(reports, [vec![7, 6, 4], vec![1, 2], vec![]]);
+
+        let err = parse_input_reports("7 6 4\n1 x2\n".as_bytes()).unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 3, "x2"));
+    }
+
+    #[test]
+    fn parses_parts() {
+        assert_eq!("a".parse(), Ok(Part::A));
+        assert_eq!("2".parse(), Ok(Part::B));
+        assert!("c".parse::<Part>().is_err());
+        assert_eq!(format!("{:<3}|", Part::B), "b  |");
+    }
+}
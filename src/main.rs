@@ -0,0 +1,1197 @@
+use aoc24::animate::{self, Command, Playback};
+use aoc24::answers::{self, Answer};
+use aoc24::batch::{self, Outcome};
+use aoc24::bench::{self, DayBench};
+use aoc24::days::{self, ReportOptions, RunResult};
+use aoc24::generate;
+use aoc24::image::{self, ImageOptions, Palette};
+use aoc24::json::Json;
+use aoc24::settings::Settings;
+use aoc24::simulation::Driver;
+use aoc24::{input, scaffold, InputSource, Part};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const USAGE: &str =
+    "usage: aoc24 run <day> [--part a|b] [--input <path> | --inputs <dir>] [--format text|json]
+                 [--metrics] [--external [--run-len <n>] [--temp-dir <path>]] [--tolerance <k>]
+                 [--policy <path>] [--min-delta <n>] [--max-delta <n>]
+                 [--direction monotone|non-strict|either] [--max-drift <n>] [--width <n>]
+                 [--height <n>]
+       aoc24 verify [day] [--answers <path>]
+       aoc24 bench [day] [--input <path>] [--iterations <n>] [--output <path>]
+       aoc24 new <day>
+       aoc24 image <day> [--part a|b] [--input <path>] [--output <dir>] [--every <n>] [--steps <n>]
+                   [--format ppm|pgm] [--scale <n>] [--palette <cell>=<rrggbb>,...]
+       aoc24 animate <day> [--part a|b] [--input <path>] [--fps <n>] [--steps <n>] [--paused]
+       aoc24 generate <day> [--seed <n>] [--size <n>] [--count <n>] [--output <path>]
+       aoc24 diff <day> [--cases <n>] [--seed <n>]
+       aoc24 report <day> [--input <path>] [--top <k>] [--tolerance <k>] [--format text|json]
+                    [--policy <path>] [--min-delta <n>] [--max-delta <n>]
+                    [--direction monotone|non-strict|either] [--max-drift <n>]";
+
+const DEFAULT_BENCH_ITERATIONS: usize = 10;
+const DEFAULT_BENCH_OUTPUT: &str = "bench.json";
+const DEFAULT_IMAGE_OUTPUT: &str = "frames";
+const DEFAULT_FPS: u32 = 10;
+const DEFAULT_DIFF_CASES: usize = 10000;
+/// Numbers per list kept in memory in external mode, 8 MB each
+const DEFAULT_RUN_LEN: usize = 1 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    /// One JSON object per line and part
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("invalid format '{s}', expected 'text' or 'json'")),
+        }
+    }
+}
+
+struct RunArgs {
+    day: u8,
+    parts: Vec<Part>,
+    input: Option<PathBuf>,
+    /// Directory of inputs to run in batch mode
+    inputs: Option<PathBuf>,
+    format: Format,
+    /// Print the metrics recorded by the solvers, they are always part of the JSON output
+    metrics: bool,
+    /// Solve without loading the input into memory
+    external: Option<ExternalArgs>,
+    settings: SettingsArgs,
+}
+
+struct ExternalArgs {
+    /// Numbers kept in memory per sorted run
+    run_len: usize,
+    /// Where the sorted runs are spilled to
+    temp_dir: PathBuf,
+}
+
+struct VerifyArgs {
+    day: Option<u8>,
+    answers: PathBuf,
+}
+
+struct BenchArgs {
+    day: Option<u8>,
+    input: Option<PathBuf>,
+    iterations: usize,
+    output: PathBuf,
+}
+
+struct ImageArgs {
+    day: u8,
+    part: Part,
+    input: Option<PathBuf>,
+    output: PathBuf,
+    /// Record every nth step, only the first and last one if not set
+    every: Option<usize>,
+    steps: Option<usize>,
+    options: ImageOptions,
+}
+
+struct AnimateArgs {
+    day: u8,
+    part: Part,
+    input: Option<PathBuf>,
+    playback: Playback,
+}
+
+struct GenerateArgs {
+    day: u8,
+    seed: u64,
+    size: Option<usize>,
+    /// Number of XMAS to plant into the day 4 word search
+    count: Option<usize>,
+    output: Option<PathBuf>,
+}
+
+struct ReportArgs {
+    day: u8,
+    input: Option<PathBuf>,
+    options: ReportOptions,
+    format: Format,
+    settings: SettingsArgs,
+}
+
+/// Where the settings of a day come from, see [`days::check_settings`].
+#[derive(Default)]
+struct SettingsArgs {
+    /// Config file with a `<key> = <value>` per line
+    config: Option<PathBuf>,
+    /// Settings given as flags, applied on top of the config file
+    flags: Settings,
+}
+
+/// Flags that change the rules of a day, shared by `run` and `report`.
+const SETTING_FLAGS: &[&str] = &[
+    "--policy",
+    "--tolerance",
+    "--min-delta",
+    "--max-delta",
+    "--direction",
+    "--max-drift",
+    "--width",
+    "--height",
+];
+
+impl SettingsArgs {
+    /// Records one of the [`SETTING_FLAGS`]. The values are only checked once the config file is
+    /// read, by the day they belong to.
+    fn parse_flag(&mut self, flag: &str, value: Option<&String>) -> Result<(), String> {
+        let value = value.ok_or_else(|| format!("{flag} requires a value"))?;
+        match flag {
+            "--policy" => self.config = Some(value.into()),
+            _ => {
+                let key = flag.trim_start_matches("--").replace('-', "_");
+                self.flags.set(&key, value);
+            }
+        }
+        Ok(())
+    }
+}
+
+struct DiffArgs {
+    day: u8,
+    cases: usize,
+    seed: u64,
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    arg.parse::<u8>().map_err(|e| format!("invalid day: {e}"))
+}
+
+fn parse_positive(flag: &str, value: Option<&String>) -> Result<usize, String> {
+    value
+        .ok_or_else(|| format!("{flag} requires a value"))?
+        .parse::<usize>()
+        .ok()
+        .filter(|&n| n > 0)
+        .ok_or_else(|| format!("{flag} must be a positive number"))
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut args = args.iter();
+    let day = parse_day(args.next().ok_or("missing day")?)?;
+
+    let mut parts = Part::ALL.to_vec();
+    let mut input = None;
+    let mut inputs = None;
+    let mut format = Format::Text;
+    let mut metrics = false;
+    let mut external = false;
+    let mut run_len = None;
+    let mut temp_dir = None;
+    let mut settings = SettingsArgs::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--metrics" => metrics = true,
+            flag if SETTING_FLAGS.contains(&flag) => settings.parse_flag(flag, args.next())?,
+            "--external" => external = true,
+            "--run-len" => run_len = Some(parse_positive("--run-len", args.next())?),
+            "--temp-dir" => {
+                temp_dir = Some(args.next().ok_or("--temp-dir requires a path")?.into());
+            }
+            "--format" => {
+                format = args.next().ok_or("--format requires a value")?.parse()?;
+            }
+            "--part" => {
+                let part = args.next().ok_or("--part requires a value")?.parse()?;
+                parts = vec![part];
+            }
+            "--input" => {
+                input = Some(args.next().ok_or("--input requires a path")?.into());
+            }
+            "--inputs" => {
+                inputs = Some(args.next().ok_or("--inputs requires a directory")?.into());
+            }
+            other => return Err(format!("unexpected argument '{other}'")),
+        }
+    }
+
+    if input.is_some() && inputs.is_some() {
+        return Err("--input and --inputs can't be combined".to_owned());
+    }
+    if metrics && inputs.is_some() && format == Format::Text {
+        return Err("--metrics can't be combined with --inputs, use --format json".to_owned());
+    }
+    if run_len.is_some() && !external {
+        return Err("--run-len requires --external".to_owned());
+    }
+    if temp_dir.is_some() && !external {
+        return Err("--temp-dir requires --external".to_owned());
+    }
+    if external && inputs.is_some() {
+        return Err("--external can't be combined with --inputs".to_owned());
+    }
+
+    Ok(RunArgs {
+        day,
+        parts,
+        input,
+        inputs,
+        format,
+        metrics,
+        external: external.then(|| ExternalArgs {
+            run_len: run_len.unwrap_or(DEFAULT_RUN_LEN),
+            temp_dir: temp_dir.unwrap_or_else(std::env::temp_dir),
+        }),
+        settings,
+    })
+}
+
+fn parse_verify_args(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut args = args.iter();
+    let mut day = None;
+    let mut answers = PathBuf::from(answers::ANSWERS_PATH);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => {
+                answers = args.next().ok_or("--answers requires a path")?.into();
+            }
+            other if day.is_none() && !other.starts_with('-') => day = Some(parse_day(other)?),
+            other => return Err(format!("unexpected argument '{other}'")),
+        }
+    }
+
+    Ok(VerifyArgs { day, answers })
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut args = args.iter();
+    let mut day = None;
+    let mut input = None;
+    let mut iterations = DEFAULT_BENCH_ITERATIONS;
+    let mut output = PathBuf::from(DEFAULT_BENCH_OUTPUT);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                input = Some(args.next().ok_or("--input requires a path")?.into());
+            }
+            "--iterations" => {
+                iterations = parse_positive("--iterations", args.next())?;
+            }
+            "--output" => {
+                output = args.next().ok_or("--output requires a path")?.into();
+            }
+            other if day.is_none() && !other.starts_with('-') => day = Some(parse_day(other)?),
+            other => return Err(format!("unexpected argument '{other}'")),
+        }
+    }
+
+    if input.is_some() && day.is_none() {
+        return Err("--input requires a day".to_owned());
+    }
+
+    Ok(BenchArgs {
+        day,
+        input,
+        iterations,
+        output,
+    })
+}
+
+fn parse_image_args(args: &[String]) -> Result<ImageArgs, String> {
+    let mut args = args.iter();
+    let day = parse_day(args.next().ok_or("missing day")?)?;
+
+    let mut part = Part::A;
+    let mut input = None;
+    let mut output = PathBuf::from(DEFAULT_IMAGE_OUTPUT);
+    let mut every = None;
+    let mut steps = None;
+    let mut options = ImageOptions::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = args.next().ok_or("--part requires a value")?.parse()?,
+            "--input" => {
+                input = Some(args.next().ok_or("--input requires a path")?.into());
+            }
+            "--output" => {
+                output = args.next().ok_or("--output requires a directory")?.into();
+            }
+            "--every" => every = Some(parse_positive("--every", args.next())?),
+            "--steps" => steps = Some(parse_positive("--steps", args.next())?),
+            "--format" => {
+                options.format = args.next().ok_or("--format requires a value")?.parse()?;
+            }
+            "--scale" => options.scale = parse_positive("--scale", args.next())?,
+            "--palette" => {
+                let spec = args.next().ok_or("--palette requires a value")?;
+                options.palette = Palette::default().with_spec(spec)?;
+            }
+            other => return Err(format!("unexpected argument '{other}'")),
+        }
+    }
+
+    Ok(ImageArgs {
+        day,
+        part,
+        input,
+        output,
+        every,
+        steps,
+        options,
+    })
+}
+
+fn parse_animate_args(args: &[String]) -> Result<AnimateArgs, String> {
+    let mut args = args.iter();
+    let day = parse_day(args.next().ok_or("missing day")?)?;
+
+    let mut part = Part::A;
+    let mut input = None;
+    let mut playback = Playback {
+        fps: DEFAULT_FPS,
+        step_limit: None,
+        start_paused: false,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = args.next().ok_or("--part requires a value")?.parse()?,
+            "--input" => {
+                input = Some(args.next().ok_or("--input requires a path")?.into());
+            }
+            "--fps" => {
+                let fps = parse_positive("--fps", args.next())?;
+                playback.fps = u32::try_from(fps)
+                    .ok()
+                    .filter(|fps| (animate::MIN_FPS..=animate::MAX_FPS).contains(fps))
+                    .ok_or(format!("--fps can be at most {}", animate::MAX_FPS))?;
+            }
+            "--steps" => playback.step_limit = Some(parse_positive("--steps", args.next())?),
+            "--paused" => playback.start_paused = true,
+            other => return Err(format!("unexpected argument '{other}'")),
+        }
+    }
+
+    Ok(AnimateArgs {
+        day,
+        part,
+        input,
+        playback,
+    })
+}
+
+fn parse_generate_args(args: &[String]) -> Result<GenerateArgs, String> {
+    let mut args = args.iter();
+    let day = parse_day(args.next().ok_or("missing day")?)?;
+
+    let mut seed = 0;
+    let mut size = None;
+    let mut count = None;
+    let mut output = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                seed = args
+                    .next()
+                    .ok_or("--seed requires a value")?
+                    .parse()
+                    .map_err(|e| format!("invalid seed: {e}"))?;
+            }
+            "--size" => size = Some(parse_positive("--size", args.next())?),
+            "--count" => {
+                count = Some(
+                    args.next()
+                        .ok_or("--count requires a value")?
+                        .parse()
+                        .map_err(|e| format!("invalid count: {e}"))?,
+                );
+            }
+            "--output" => {
+                output = Some(args.next().ok_or("--output requires a path")?.into());
+            }
+            other => return Err(format!("unexpected argument '{other}'")),
+        }
+    }
+    if count.is_some() && day != 4 {
+        return Err("--count is only supported for day 4".to_owned());
+    }
+
+    Ok(GenerateArgs {
+        day,
+        seed,
+        size,
+        count,
+        output,
+    })
+}
+
+fn parse_diff_args(args: &[String]) -> Result<DiffArgs, String> {
+    let mut args = args.iter();
+    let day = parse_day(args.next().ok_or("missing day")?)?;
+
+    let mut cases = DEFAULT_DIFF_CASES;
+    let mut seed = 0;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--cases" => cases = parse_positive("--cases", args.next())?,
+            "--seed" => {
+                seed = args
+                    .next()
+                    .ok_or("--seed requires a value")?
+                    .parse()
+                    .map_err(|e| format!("invalid seed: {e}"))?;
+            }
+            other => return Err(format!("unexpected argument '{other}'")),
+        }
+    }
+
+    Ok(DiffArgs { day, cases, seed })
+}
+
+fn parse_report_args(args: &[String]) -> Result<ReportArgs, String> {
+    let mut args = args.iter();
+    let day = parse_day(args.next().ok_or("missing day")?)?;
+
+    let mut input = None;
+    let mut options = ReportOptions::default();
+    let mut format = Format::Text;
+    let mut settings = SettingsArgs::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            flag if SETTING_FLAGS.contains(&flag) => settings.parse_flag(flag, args.next())?,
+            "--input" => {
+                input = Some(args.next().ok_or("--input requires a path")?.into());
+            }
+            "--top" => options.top_k = parse_positive("--top", args.next())?,
+            "--format" => {
+                format = args.next().ok_or("--format requires a value")?.parse()?;
+            }
+            other => return Err(format!("unexpected argument '{other}'")),
+        }
+    }
+
+    Ok(ReportArgs {
+        day,
+        input,
+        options,
+        format,
+        settings,
+    })
+}
+
+fn usage_error(e: String) -> ! {
+    eprintln!("error: {e}");
+    eprintln!("{USAGE}");
+    exit(2);
+}
+
+fn run(
+    RunArgs {
+        day,
+        parts,
+        input,
+        inputs,
+        format,
+        metrics,
+        external,
+        settings,
+    }: RunArgs,
+) {
+    let settings = load_settings(day, settings);
+
+    if let Some(dir) = inputs {
+        return run_batch(day, &dir, &parts, &settings, format);
+    }
+    if let Some(external) = external {
+        return run_external(day, input.as_deref(), &parts, &external, format);
+    }
+
+    let input = InputSource::resolve(day, input.as_deref())
+        .load()
+        .unwrap_or_else(|e| {
+            eprintln!("error: {e}");
+            exit(1);
+        });
+
+    let result = match days::run_with(day, &input, &parts, &settings) {
+        Some(Ok(result)) => result,
+        Some(Err(e)) => {
+            eprintln!("error: {e}");
+            exit(1);
+        }
+        None => {
+            eprintln!("error: no solution for day {day}");
+            exit(1);
+        }
+    };
+
+    print_result(day, result, format, metrics);
+}
+
+/// Runs a day on an input that is read line by line instead of being loaded into memory.
+fn run_external(
+    day: u8,
+    input: Option<&Path>,
+    parts: &[Part],
+    ExternalArgs { run_len, temp_dir }: &ExternalArgs,
+    format: Format,
+) {
+    let source = InputSource::resolve(day, input);
+    let reader = source.open().unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        exit(1);
+    });
+
+    let lines = input::sanitized_lines(reader);
+    match days::run_external(day, lines, parts, temp_dir, *run_len) {
+        Some(Ok(result)) => print_result(day, result, format, false),
+        Some(Err(e)) => {
+            eprintln!("error: {e}");
+            exit(1);
+        }
+        None => {
+            eprintln!(
+                "error: day {day} can't run on inputs larger than memory, available: {}",
+                days::EXTERNAL
+                    .iter()
+                    .map(u8::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            exit(1);
+        }
+    }
+}
+
+fn print_result(day: u8, result: RunResult, format: Format, metrics: bool) {
+    match format {
+        Format::Text => {
+            for part_result in result.parts {
+                println!(
+                    "Day {day}, part {}: {}",
+                    part_result.part,
+                    part_result.answer_text()
+                );
+                if metrics {
+                    for (name, metric) in part_result.metrics.iter() {
+                        println!("  {name}: {metric}");
+                    }
+                }
+            }
+        }
+        Format::Json => {
+            for json in result.to_json(day) {
+                println!("{json}");
+            }
+        }
+    }
+}
+
+fn run_batch(day: u8, dir: &Path, parts: &[Part], settings: &Settings, format: Format) {
+    let files = batch::input_files(dir).unwrap_or_else(|e| {
+        eprintln!("error: failed to list {}: {e}", dir.display());
+        exit(1);
+    });
+    if files.is_empty() {
+        eprintln!("error: no input files in {}", dir.display());
+        exit(1);
+    }
+
+    // Panics are reported in the table, only keep their location instead of full backtraces of
+    // concurrently failing inputs
+    std::panic::set_hook(Box::new(|info| eprintln!("{info}")));
+    let outcomes = batch::run_batch(day, &files, parts, settings).unwrap_or_else(|| {
+        eprintln!("error: no solution for day {day}");
+        exit(1);
+    });
+
+    let mut rows = Vec::new();
+    for (path, outcome) in files.iter().zip(&outcomes) {
+        let input = path.display().to_string();
+        match (outcome, format) {
+            (Outcome::Solved(result), Format::Text) => {
+                for part_result in &result.parts {
+                    rows.push([
+                        input.clone(),
+                        part_result.part.to_string(),
+                        part_result.answer_text(),
+                        format!("{:.3}", bench::millis(result.parse_time)),
+                        format!("{:.3}", bench::millis(part_result.solve_time)),
+                        outcome.to_string(),
+                    ]);
+                }
+            }
+            (_, Format::Text) => rows.push([
+                input,
+                "-".to_owned(),
+                "-".to_owned(),
+                "-".to_owned(),
+                "-".to_owned(),
+                format!("FAILED, {outcome}"),
+            ]),
+            (Outcome::Solved(result), Format::Json) => {
+                for json in result.to_json(day) {
+                    let Json::Object(mut fields) = json else {
+                        unreachable!("results are objects");
+                    };
+                    fields.insert(1, ("input".to_owned(), input.clone().into()));
+                    println!("{}", Json::Object(fields));
+                }
+            }
+            (_, Format::Json) => {
+                let json = Json::object([
+                    ("day", day.into()),
+                    ("input", input.into()),
+                    ("error", outcome.to_string().into()),
+                ]);
+                println!("{json}");
+            }
+        }
+    }
+
+    if format == Format::Text {
+        let header =
+            ["input", "part", "answer", "parse ms", "solve ms", "status"].map(String::from);
+        let widths = (0..header.len())
+            .map(|col| {
+                std::iter::once(&header)
+                    .chain(&rows)
+                    .map(|row| row[col].len())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+        for row in std::iter::once(&header).chain(&rows) {
+            println!(
+                "{:<w0$}  {:<w1$}  {:>w2$}  {:>w3$}  {:>w4$}  {}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                row[4],
+                row[5],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3],
+                w4 = widths[4],
+            );
+        }
+    }
+
+    let failures = outcomes
+        .iter()
+        .filter(|outcome| !matches!(outcome, Outcome::Solved(_)))
+        .count();
+    if failures > 0 {
+        eprintln!("{failures} of {} inputs failed", outcomes.len());
+        exit(1);
+    }
+}
+
+fn verify(VerifyArgs { day, answers }: VerifyArgs) {
+    let registry = InputSource::File(answers.clone())
+        .load()
+        .unwrap_or_else(|e| {
+            eprintln!("error: {e}");
+            exit(1);
+        });
+    let registry = answers::parse_answers(&registry).unwrap_or_else(|e| {
+        eprintln!("error: {}: {e}", answers.display());
+        exit(1);
+    });
+    let selected = registry
+        .into_iter()
+        .filter(|answer| day.is_none_or(|day| answer.day == day))
+        .collect::<Vec<Answer>>();
+
+    if selected.is_empty() {
+        let scope = day.map(|day| format!(" for day {day}")).unwrap_or_default();
+        eprintln!(
+            "error: no answers registered{scope} in {}",
+            answers.display()
+        );
+        exit(1);
+    }
+
+    let results = answers::verify(&selected);
+    let input_width = results
+        .iter()
+        .map(|(answer, _)| answer.input.display().to_string().len())
+        .max()
+        .unwrap_or(0)
+        .max("input".len());
+
+    println!(
+        "{:>3}  {:<4}  {:<input_width$}  {:>16}  {:>16}  result",
+        "day", "part", "input", "expected", "actual"
+    );
+    for (answer, verdict) in &results {
+        let actual = match verdict {
+            answers::Verdict::Pass => answer.expected_text(),
+            answers::Verdict::Fail { actual } => {
+                actual.map_or_else(|| "none".to_owned(), |actual| actual.to_string())
+            }
+            _ => "-".to_owned(),
+        };
+        println!(
+            "{:>3}  {:<4}  {:<input_width$}  {:>16}  {:>16}  {verdict}",
+            answer.day,
+            answer.part,
+            answer.input.display().to_string(),
+            answer.expected_text(),
+            actual,
+        );
+    }
+
+    let failures = results
+        .iter()
+        .filter(|(_, verdict)| verdict.is_failure())
+        .count();
+    if failures > 0 {
+        println!("{failures} of {} checks failed", results.len());
+        exit(1);
+    }
+}
+
+fn bench(
+    BenchArgs {
+        day,
+        input,
+        iterations,
+        output,
+    }: BenchArgs,
+) {
+    // Without an explicit day only days with an input file are benchmarked, stdin can only be read
+    // once.
+    let sources = match day {
+        Some(day) => vec![(day, InputSource::resolve(day, input.as_deref()))],
+        None => days::DAYS
+            .iter()
+            .map(|&day| (day, input::default_input_path(day)))
+            .filter(|(_, path)| path.is_file())
+            .map(|(day, path)| (day, InputSource::File(path)))
+            .collect(),
+    };
+
+    if sources.is_empty() {
+        eprintln!("error: no inputs found in {}/", input::INPUT_DIR);
+        exit(1);
+    }
+
+    println!(
+        "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+        "day", "phase", "min ms", "median ms", "p95 ms"
+    );
+
+    let mut results = Vec::new();
+    for (day, source) in sources {
+        let input = source.load().unwrap_or_else(|e| {
+            eprintln!("error: {e}");
+            exit(1);
+        });
+        let result: DayBench = match bench::bench_day(day, &input, iterations) {
+            Some(Ok(result)) => result,
+            Some(Err(e)) => {
+                eprintln!("error: {source}: {e}");
+                exit(1);
+            }
+            None => {
+                eprintln!("error: no solution for day {day}");
+                exit(1);
+            }
+        };
+
+        for (phase, stats) in &result.phases {
+            println!(
+                "{day:>3}  {phase:<6}  {:>12.3}  {:>12.3}  {:>12.3}",
+                bench::millis(stats.min),
+                bench::millis(stats.median),
+                bench::millis(stats.p95),
+            );
+        }
+        results.push(result.to_json(&source.to_string()));
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.as_secs())
+        .unwrap_or_default();
+    let json = Json::object([
+        ("timestamp", timestamp.into()),
+        ("iterations", iterations.into()),
+        ("days", Json::Array(results)),
+    ]);
+    if let Err(e) = std::fs::write(&output, format!("{json}\n")) {
+        eprintln!("error: failed to write {}: {e}", output.display());
+        exit(1);
+    }
+    println!("wrote results to {}", output.display());
+}
+
+/// Generates the module of a new day and registers it, has to be run from the repository root.
+fn new_day(day: u8) {
+    let fail = |e: String| -> ! {
+        eprintln!("error: {e}");
+        exit(1);
+    };
+
+    let mod_rs_path = PathBuf::from(scaffold::DAYS_DIR).join("mod.rs");
+    let module_path = scaffold::module_path(day);
+    if module_path.exists() {
+        fail(format!("{} already exists", module_path.display()));
+    }
+
+    let mod_rs = std::fs::read_to_string(&mod_rs_path).unwrap_or_else(|e| {
+        fail(format!(
+            "failed to read {}, run this from the repository root: {e}",
+            mod_rs_path.display()
+        ))
+    });
+    let mod_rs = scaffold::register_day(&mod_rs, day).unwrap_or_else(|e| fail(e));
+
+    let mut answers = std::fs::read_to_string(answers::ANSWERS_PATH).unwrap_or_default();
+    if !answers.is_empty() && !answers.ends_with('\n') {
+        answers.push('\n');
+    }
+    answers.push_str(&scaffold::answers_entries(day));
+
+    let example_path = scaffold::example_path(day);
+    let mut files = vec![
+        (module_path.as_path(), scaffold::day_module(day)),
+        (mod_rs_path.as_path(), mod_rs),
+        (Path::new(answers::ANSWERS_PATH), answers),
+    ];
+    // The tests of the module include the example, so it has to exist even while it is empty
+    if !example_path.exists() {
+        files.push((example_path.as_path(), String::new()));
+    }
+    for (path, contents) in files {
+        std::fs::write(path, contents)
+            .unwrap_or_else(|e| fail(format!("failed to write {}: {e}", path.display())));
+        println!("wrote {}", path.display());
+    }
+    println!(
+        "add the example to {} and the answers to {}",
+        example_path.display(),
+        answers::ANSWERS_PATH
+    );
+}
+
+/// Runs the simulation of a day and writes the recorded frames as images.
+fn image(
+    ImageArgs {
+        day,
+        part,
+        input,
+        output,
+        every,
+        steps,
+        options,
+    }: ImageArgs,
+) {
+    let input = InputSource::resolve(day, input.as_deref())
+        .load()
+        .unwrap_or_else(|e| {
+            eprintln!("error: {e}");
+            exit(1);
+        });
+
+    let mut driver = Driver::new().record_every(every.unwrap_or(usize::MAX));
+    if let Some(steps) = steps {
+        driver = driver.step_limit(steps);
+    }
+    let run = match days::simulate(day, &input, part, &driver) {
+        Some(Ok(run)) => run,
+        Some(Err(e)) => {
+            eprintln!("error: {e}");
+            exit(1);
+        }
+        None => {
+            eprintln!(
+                "error: no simulation for day {day}, only for days {:?}",
+                days::SIMULATIONS
+            );
+            exit(1);
+        }
+    };
+
+    let prefix = format!("day{day:02}{part}-");
+    let paths =
+        image::write_frames(&run.recording, &output, &prefix, &options).unwrap_or_else(|e| {
+            eprintln!("error: failed to write frames to {}: {e}", output.display());
+            exit(1);
+        });
+    println!(
+        "wrote {} frames of {} steps to {}",
+        paths.len(),
+        run.steps,
+        output.display()
+    );
+}
+
+/// Writes a generated input to stdout or a file. The planted answers go to stderr, as entries for
+/// the answers file if the input was written to a file.
+fn generate(
+    GenerateArgs {
+        day,
+        seed,
+        size,
+        count,
+        output,
+    }: GenerateArgs,
+) {
+    let Some((default_size, unit)) = generate::default_size(day) else {
+        eprintln!("error: no generator for day {day}");
+        exit(1);
+    };
+    let generated = match count {
+        Some(count) => {
+            let side = size.unwrap_or(default_size).max(4);
+            generate::word_search(seed, side, count).unwrap_or_else(|| {
+                eprintln!(
+                    "error: at most {} XMAS fit into a grid side of {side}",
+                    generate::max_xmas(side)
+                );
+                exit(1);
+            })
+        }
+        None => generate::generate(day, seed, size).expect("day has a generator"),
+    };
+    eprintln!(
+        "generated day {day} with seed {seed}, {unit}: {}",
+        size.unwrap_or(default_size)
+    );
+
+    let Some(output) = output else {
+        print!("{}", generated.input);
+        for (part, answer) in &generated.answers {
+            eprintln!("planted answer for day {day}, part {part}: {answer}");
+        }
+        return;
+    };
+
+    if let Err(e) = std::fs::write(&output, &generated.input) {
+        eprintln!("error: failed to write {}: {e}", output.display());
+        exit(1);
+    }
+    eprintln!("wrote {}", output.display());
+    for (part, answer) in &generated.answers {
+        eprintln!(
+            "{day:<5} {part:<4} {:<30} {answer}",
+            output.display().to_string()
+        );
+    }
+}
+
+/// Reads the config file, if there is one, and applies the settings given as flags. Only the
+/// result has to be valid for the day.
+fn load_settings(day: u8, SettingsArgs { config, flags }: SettingsArgs) -> Settings {
+    let mut settings = match config {
+        Some(path) => {
+            let config = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+                eprintln!("error: failed to read {}: {e}", path.display());
+                exit(1);
+            });
+            Settings::from_config(&config).unwrap_or_else(|e| {
+                eprintln!("error: {}: {e}", path.display());
+                exit(1);
+            })
+        }
+        None => Settings::default(),
+    };
+    settings.apply(&flags);
+    if let Err(e) = days::check_settings(day, &settings) {
+        eprintln!("error: invalid settings: {e}");
+        exit(1);
+    }
+    settings
+}
+
+/// Prints the breakdown of the answers of a day.
+fn report(
+    ReportArgs {
+        day,
+        input,
+        mut options,
+        format,
+        settings,
+    }: ReportArgs,
+) {
+    options.settings = load_settings(day, settings);
+
+    let input = InputSource::resolve(day, input.as_deref())
+        .load()
+        .unwrap_or_else(|e| {
+            eprintln!("error: {e}");
+            exit(1);
+        });
+
+    match days::report(day, &input, &options) {
+        Some(Ok(report)) => match format {
+            Format::Text => println!("{report}"),
+            Format::Json => println!("{}", report.to_json()),
+        },
+        Some(Err(e)) => {
+            eprintln!("error: {e}");
+            exit(1);
+        }
+        None => {
+            eprintln!("error: day {day} has no report");
+            exit(1);
+        }
+    }
+}
+
+/// Runs both implementations of a day on generated inputs and reports the first input they
+/// disagree on. Exits with 1 if there is one.
+fn diff(DiffArgs { day, cases, seed }: DiffArgs) {
+    // Panics are reported as part of the divergence, not in between
+    std::panic::set_hook(Box::new(|_| {}));
+    let divergence = days::differential(day, cases, seed);
+    let _ = std::panic::take_hook();
+
+    match divergence {
+        None => {
+            eprintln!(
+                "error: day {day} has no differential test, available: {}",
+                days::DIFFERENTIALS
+                    .iter()
+                    .map(u8::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            exit(1);
+        }
+        Some(None) => println!("day {day}: {cases} cases with seed {seed} agree"),
+        Some(Some(divergence)) => {
+            println!("day {day}: {divergence}");
+            exit(1);
+        }
+    }
+}
+
+/// Switches the terminal between reading single key presses without echoing them and its normal
+/// line based mode. Best effort, without `stty` keys have to be confirmed with enter.
+fn set_key_mode(single_keys: bool) {
+    let Ok(tty) = std::fs::File::open("/dev/tty") else {
+        return;
+    };
+    let settings: &[&str] = if single_keys {
+        &["-icanon", "-echo", "min", "1"]
+    } else {
+        &["icanon", "echo"]
+    };
+    let _ = std::process::Command::new("stty")
+        .args(settings)
+        .stdin(tty)
+        .status();
+}
+
+/// Plays the simulation of a day in the terminal, controlled by keys read from stdin.
+fn animate(
+    AnimateArgs {
+        day,
+        part,
+        input,
+        playback,
+    }: AnimateArgs,
+) {
+    let source = InputSource::resolve(day, input.as_deref());
+    if source == InputSource::Stdin {
+        eprintln!(
+            "error: no input file for day {day}, stdin is needed for the controls, use --input"
+        );
+        exit(1);
+    }
+    let input = source.load().unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        exit(1);
+    });
+
+    let mut simulation = match days::simulation(day, &input, part) {
+        Some(Ok(simulation)) => simulation,
+        Some(Err(e)) => {
+            eprintln!("error: {e}");
+            exit(1);
+        }
+        None => {
+            eprintln!(
+                "error: no simulation for day {day}, only for days {:?}",
+                days::SIMULATIONS
+            );
+            exit(1);
+        }
+    };
+
+    let (sender, commands) = std::sync::mpsc::channel();
+    // Blocks on stdin until the process exits, so it is never joined
+    std::thread::spawn(move || {
+        for key in std::io::stdin().lock().bytes() {
+            let Some(command) = key.ok().and_then(Command::from_key) else {
+                continue;
+            };
+            if sender.send(command).is_err() {
+                break;
+            }
+        }
+    });
+
+    set_key_mode(true);
+    let res = animate::animate(
+        simulation.as_mut(),
+        playback,
+        &commands,
+        std::io::stdout().lock(),
+    );
+    set_key_mode(false);
+    if let Err(e) = res {
+        eprintln!("error: failed to draw the animation: {e}");
+        exit(1);
+    }
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    match args.split_first() {
+        Some((command, rest)) if command == "run" => {
+            run(parse_run_args(rest).unwrap_or_else(|e| usage_error(e)))
+        }
+        Some((command, rest)) if command == "verify" => {
+            verify(parse_verify_args(rest).unwrap_or_else(|e| usage_error(e)))
+        }
+        Some((command, [day])) if command == "new" => {
+            new_day(parse_day(day).unwrap_or_else(|e| usage_error(e)))
+        }
+        Some((command, rest)) if command == "bench" => {
+            bench(parse_bench_args(rest).unwrap_or_else(|e| usage_error(e)))
+        }
+        Some((command, rest)) if command == "image" => {
+            image(parse_image_args(rest).unwrap_or_else(|e| usage_error(e)))
+        }
+        Some((command, rest)) if command == "generate" => {
+            generate(parse_generate_args(rest).unwrap_or_else(|e| usage_error(e)))
+        }
+        Some((command, rest)) if command == "report" => {
+            report(parse_report_args(rest).unwrap_or_else(|e| usage_error(e)))
+        }
+        Some((command, rest)) if command == "diff" => {
+            diff(parse_diff_args(rest).unwrap_or_else(|e| usage_error(e)))
+        }
+        Some((command, rest)) if command == "animate" => {
+            animate(parse_animate_args(rest).unwrap_or_else(|e| usage_error(e)))
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            exit(2);
+        }
+    }
+}
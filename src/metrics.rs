@@ -0,0 +1,222 @@
+use crate::json::Json;
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+
+/// Internal counts of a solver, recorded with [`count`], [`gauge`] and [`record`] while it runs
+/// inside [`capture`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metrics {
+    /// In the order they were first recorded
+    metrics: Vec<(&'static str, Metric)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// Sum of everything counted
+    Counter(u64),
+    /// Last value set
+    Gauge(i64),
+    Histogram(Histogram),
+}
+
+/// Summary of the distribution of recorded values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Histogram {
+    pub count: u64,
+    pub sum: u64,
+    pub min: u64,
+    pub max: u64,
+}
+
+impl Histogram {
+    fn new(value: u64) -> Self {
+        Histogram {
+            count: 1,
+            sum: value,
+            min: value,
+            max: value,
+        }
+    }
+
+    fn record(&mut self, value: u64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.sum as f64 / self.count as f64
+    }
+}
+
+impl Metrics {
+    pub fn is_empty(&self) -> bool {
+        self.metrics.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<Metric> {
+        self.metrics
+            .iter()
+            .find(|(metric_name, _)| *metric_name == name)
+            .map(|&(_, metric)| metric)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, Metric)> + '_ {
+        self.metrics.iter().copied()
+    }
+
+    fn update(&mut self, name: &'static str, new: Metric, merge: impl FnOnce(&mut Metric)) {
+        match self
+            .metrics
+            .iter_mut()
+            .find(|(metric_name, _)| *metric_name == name)
+        {
+            Some((_, metric)) => merge(metric),
+            None => self.metrics.push((name, new)),
+        }
+    }
+
+    /// An object with a field per metric, histograms are objects of their summary.
+    pub fn to_json(&self) -> Json {
+        Json::object(self.metrics.iter().map(|&(name, metric)| {
+            let value = match metric {
+                Metric::Counter(count) => count.into(),
+                Metric::Gauge(value) => value.into(),
+                Metric::Histogram(histogram) => Json::object([
+                    ("count", histogram.count.into()),
+                    ("min", histogram.min.into()),
+                    ("max", histogram.max.into()),
+                    ("mean", histogram.mean().into()),
+                ]),
+            };
+            (name, value)
+        }))
+    }
+}
+
+impl Display for Metric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Metric::Counter(count) => write!(f, "{count}"),
+            Metric::Gauge(value) => write!(f, "{value}"),
+            Metric::Histogram(histogram) => write!(
+                f,
+                "{} values, min {}, mean {:.2}, max {}",
+                histogram.count,
+                histogram.min,
+                histogram.mean(),
+                histogram.max
+            ),
+        }
+    }
+}
+
+thread_local! {
+    /// Metrics of the innermost running [`capture`] on this thread
+    static CURRENT: RefCell<Option<Metrics>> = const { RefCell::new(None) };
+}
+
+/// Restores the metrics of an outer [`capture`] even if the captured function panics.
+struct Restore(Option<Metrics>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+    }
+}
+
+/// Runs `f` and returns the metrics it recorded on this thread. Captures can be nested, the
+/// metrics of the inner one are not part of the outer one.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Metrics) {
+    let outer = CURRENT.with(|current| current.replace(Some(Metrics::default())));
+    let restore = Restore(outer);
+    let res = f();
+    let metrics = CURRENT
+        .with(|current| current.replace(None))
+        .unwrap_or_default();
+    drop(restore);
+    (res, metrics)
+}
+
+fn with_current(f: impl FnOnce(&mut Metrics)) {
+    CURRENT.with(|current| {
+        if let Some(metrics) = current.borrow_mut().as_mut() {
+            f(metrics);
+        }
+    });
+}
+
+/// Adds `n` to the counter `name`. Like all recording functions it does nothing outside of
+/// [`capture`], so solvers can record unconditionally.
+pub fn count(name: &'static str, n: u64) {
+    with_current(|metrics| {
+        metrics.update(name, Metric::Counter(n), |metric| {
+            if let Metric::Counter(count) = metric {
+                *count += n;
+            }
+        })
+    });
+}
+
+/// Sets the gauge `name` to `value`.
+pub fn gauge(name: &'static str, value: i64) {
+    with_current(|metrics| {
+        metrics.update(name, Metric::Gauge(value), |metric| {
+            *metric = Metric::Gauge(value)
+        })
+    });
+}
+
+/// Adds `value` to the histogram `name`.
+pub fn record(name: &'static str, value: u64) {
+    with_current(|metrics| {
+        metrics.update(name, Metric::Histogram(Histogram::new(value)), |metric| {
+            if let Metric::Histogram(histogram) = metric {
+                histogram.record(value);
+            }
+        })
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_nested_metrics() {
+        count("outside", 1);
+        let ((), metrics) = capture(|| {
+            count("tries", 2);
+            gauge("size", 5);
+            let ((), inner) = capture(|| count("tries", 100));
+            assert_eq!(inner.get("tries"), Some(Metric::Counter(100)));
+            count("tries", 3);
+            gauge("size", -1);
+            for value in [4, 1, 7] {
+                record("lengths", value);
+            }
+        });
+
+        assert_eq!(metrics.get("outside"), None);
+        assert_eq!(metrics.get("tries"), Some(Metric::Counter(5)));
+        assert_eq!(metrics.get("size"), Some(Metric::Gauge(-1)));
+        assert_eq!(
+            metrics.to_json().to_string(),
+            r#"{"tries":5,"size":-1,"lengths":{"count":3,"min":1,"max":7,"mean":4}}"#
+        );
+        assert_eq!(
+            metrics.get("lengths").unwrap().to_string(),
+            "3 values, min 1, mean 4.00, max 7"
+        );
+    }
+
+    #[test]
+    fn panics_end_capture() {
+        let res = std::panic::catch_unwind(|| capture(|| panic!("solver failed")));
+        assert!(res.is_err());
+        let ((), metrics) = capture(|| count("tries", 1));
+        assert_eq!(metrics.get("tries"), Some(Metric::Counter(1)));
+        CURRENT.with(|current| assert!(current.borrow().is_none()));
+    }
+}
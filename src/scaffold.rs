@@ -0,0 +1,164 @@
+use std::path::PathBuf;
+
+/// Directory of the day modules, relative to the repository root.
+pub const DAYS_DIR: &str = "src/days";
+
+pub fn module_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("{DAYS_DIR}/day{day:02}.rs"))
+}
+
+pub fn example_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/examples/day{day:02}.txt"))
+}
+
+/// Source of a new day module. It parses the input as character grid and comes with an ignored
+/// test of the example at [`example_path`] to fill in.
+pub fn day_module(day: u8) -> String {
+    format!(
+        r#"use crate::{{Grid, ParseError, Solution}};
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    const DAY: u8 = {day};
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        Grid::parse_chars(input)
+    }}
+
+    fn part_a(grid: &Self::Input) -> Option<u64> {{
+        todo!("solve part a on a grid of size {{}}", grid.size())
+    }}
+
+    fn part_b(grid: &Self::Input) -> Option<u64> {{
+        todo!("solve part b on a grid of size {{}}", grid.size())
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day{day:02}.txt");
+
+    #[test]
+    #[ignore = "example input and answers missing"]
+    fn example() {{
+        let input = Day{day:02}::parse(EXAMPLE).unwrap();
+        assert_eq!(Day{day:02}::part_a(&input), Some(0));
+        assert_eq!(Day{day:02}::part_b(&input), Some(0));
+    }}
+}}
+"#
+    )
+}
+
+/// Adds `day` to the `days!` list in the source of `src/days/mod.rs`, which declares its module
+/// and dispatches to its solution.
+pub fn register_day(mod_rs: &str, day: u8) -> Result<String, String> {
+    let mut lines = mod_rs.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|&line| line == "days! {")
+        .ok_or("no days! list found")?
+        + 1;
+    let len = lines[start..]
+        .iter()
+        .position(|&line| line == "}")
+        .ok_or("days! list isn't closed")?;
+
+    let days = lines[start..start + len]
+        .iter()
+        .map(|line| {
+            line.trim_start()
+                .split_once(" => ")
+                .and_then(|(listed, _)| listed.parse::<u8>().ok())
+                .ok_or_else(|| format!("invalid entry in days! list: '{}'", line.trim()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if days.contains(&day) {
+        return Err(format!("day {day} is already registered"));
+    }
+
+    // The list is sorted, so insert in front of the first day that comes later
+    let entry = format!("    {day} => day{day:02}::Day{day:02},");
+    let idx = days.iter().filter(|&&listed| listed < day).count();
+    lines.insert(start + idx, &entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Registry entries for the example answers of `day`, to be appended to the answers file. The
+/// expected values are placeholders, `verify` fails on them until they are filled in.
+pub fn answers_entries(day: u8) -> String {
+    let example = example_path(day);
+    format!(
+        "# TODO: day {day}, replace the placeholder answers once {} has the example\n\
+         {day:<5} a    {:<30} 0\n\
+         {day:<5} b    {:<30} 0\n",
+        example.display(),
+        example.display(),
+        example.display(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    const MOD_RS: &str = "use crate::Solution;
+
+days! {
+    1 => day01::Day01,
+    3 => day03::Day03,
+}
+
+pub const SIMULATIONS: &[u8] = &[6];
+";
+
+    #[test]
+    fn registers_day_in_order() {
+        let registered = register_day(MOD_RS, 2).unwrap();
+        assert!(registered.contains(
+            "days! {\n    1 => day01::Day01,\n    2 => day02::Day02,\n    3 => day03::Day03,\n}\n"
+        ));
+        assert!(register_day(&registered, 2).is_err());
+
+        let appended = register_day(MOD_RS, 16).unwrap();
+        assert!(appended.contains("    3 => day03::Day03,\n    16 => day16::Day16,\n}\n"));
+        assert!(appended.ends_with("\npub const SIMULATIONS: &[u8] = &[6];\n"));
+    }
+
+    #[test]
+    fn registers_in_days_module() {
+        let day = (1..=25)
+            .find(|day| !crate::days::DAYS.contains(day))
+            .unwrap();
+        let registered = register_day(include_str!("days/mod.rs"), day).unwrap();
+
+        let listed = registered
+            .lines()
+            .skip_while(|&line| line != "days! {")
+            .skip(1)
+            .take_while(|&line| line != "}")
+            .map(|line| line.trim_start().split(' ').next().unwrap())
+            .collect::<Vec<_>>();
+        let expected = crate::days::DAYS
+            .iter()
+            .chain([&day])
+            .sorted()
+            .map(u8::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(listed, expected);
+    }
+
+    #[test]
+    fn registry_entries_parse() {
+        let answers = crate::answers::parse_answers(&answers_entries(16)).unwrap();
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[0].input, example_path(16));
+    }
+}
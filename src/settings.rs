@@ -0,0 +1,76 @@
+/// Settings that change the rules of a day, as `key = value` pairs given as flags or read from a
+/// config file. Every day reads the keys that apply to it into its own options, see
+/// [`crate::days::check_settings`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Settings {
+    /// In the order they were first set
+    values: Vec<(String, String)>,
+}
+
+impl Settings {
+    /// Reads lines of `<key> = <value>`, `#` starts a comment.
+    pub fn from_config(config: &str) -> Result<Self, String> {
+        let mut settings = Settings::default();
+        for (idx, line) in config.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected '<key> = <value>'", idx + 1))?;
+            settings.set(key.trim(), value.trim());
+        }
+        Ok(settings)
+    }
+
+    /// Sets `key` to `value`, replacing an earlier value.
+    pub fn set(&mut self, key: &str, value: &str) {
+        match self.values.iter_mut().find(|(set_key, _)| set_key == key) {
+            Some((_, set_value)) => *set_value = value.to_owned(),
+            None => self.values.push((key.to_owned(), value.to_owned())),
+        }
+    }
+
+    /// Sets all of `other` on top of these settings.
+    pub fn apply(&mut self, other: &Settings) {
+        for (key, value) in other.iter() {
+            self.set(key, value);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> + '_ {
+        self.values
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_config_and_overrides() {
+        let mut settings =
+            Settings::from_config("# sensors of class B\nmin_delta = 0\nmax_delta=5 \n\n").unwrap();
+        let mut flags = Settings::default();
+        flags.set("max_delta", "9");
+        flags.set("tolerance", "2");
+        settings.apply(&flags);
+        assert_eq!(
+            settings.iter().collect::<Vec<_>>(),
+            [("min_delta", "0"), ("max_delta", "9"), ("tolerance", "2")]
+        );
+
+        assert_eq!(
+            Settings::from_config("max_delta = 3\nspeed\n"),
+            Err("line 2: expected '<key> = <value>'".to_owned())
+        );
+    }
+}
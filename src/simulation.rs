@@ -0,0 +1,278 @@
+use crate::Grid;
+use std::fmt::{Display, Formatter};
+
+/// A puzzle that is solved by advancing some state one step at a time, like the guard of day 6,
+/// the robots of day 14 or the warehouse of day 15.
+pub trait Simulation {
+    type State;
+
+    fn state(&self) -> &Self::State;
+
+    /// Advances the simulation by one step. Must not be called once [`Simulation::is_done`]
+    /// returns true.
+    fn step(&mut self);
+
+    fn is_done(&self) -> bool;
+
+    /// Draws the current state as one character per cell.
+    fn render(&self) -> Grid<char>;
+
+    /// A short summary of the current state, like the position of a robot.
+    fn status(&self) -> String {
+        String::new()
+    }
+}
+
+/// The parts of a [`Simulation`] that don't depend on the type of its state, so simulations of
+/// different days can be used through the same trait object.
+pub trait DynSimulation {
+    fn step(&mut self);
+
+    fn is_done(&self) -> bool;
+
+    fn render(&self) -> Grid<char>;
+
+    fn status(&self) -> String;
+}
+
+impl<S: Simulation> DynSimulation for S {
+    fn step(&mut self) {
+        Simulation::step(self)
+    }
+
+    fn is_done(&self) -> bool {
+        Simulation::is_done(self)
+    }
+
+    fn render(&self) -> Grid<char> {
+        Simulation::render(self)
+    }
+
+    fn status(&self) -> String {
+        Simulation::status(self)
+    }
+}
+
+/// Why [`Driver::run_until`] stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The simulation reported that it is done.
+    Done,
+    /// The step limit of the driver was reached.
+    StepLimit,
+    /// The stop predicate matched the state.
+    Predicate,
+}
+
+/// The rendered state of a simulation after `step` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub step: usize,
+    pub grid: Grid<char>,
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "step {}", self.step)?;
+        write!(f, "{}", self.grid)
+    }
+}
+
+/// Frames recorded by a [`Driver`], in the order they were taken.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recording {
+    frames: Vec<Frame>,
+}
+
+impl Recording {
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The last frame taken at or before `step`.
+    pub fn frame_at(&self, step: usize) -> Option<&Frame> {
+        let idx = self.frames.partition_point(|frame| frame.step <= step);
+        idx.checked_sub(1).map(|idx| &self.frames[idx])
+    }
+
+    /// Plays the recorded frames back, calling `show` for every frame in order.
+    pub fn replay(&self, mut show: impl FnMut(&Frame)) {
+        for frame in &self.frames {
+            show(frame);
+        }
+    }
+}
+
+/// Outcome of running a simulation with a [`Driver`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub steps: usize,
+    pub stop: Stop,
+    pub recording: Recording,
+}
+
+/// Runs simulations up to an optional step limit, optionally recording every Nth frame.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Driver {
+    step_limit: Option<usize>,
+    record_every: Option<usize>,
+}
+
+impl Driver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops after at most `step_limit` steps, even if the simulation isn't done yet.
+    pub fn step_limit(mut self, step_limit: usize) -> Self {
+        self.step_limit = Some(step_limit);
+        self
+    }
+
+    /// Like [`Driver::step_limit`], but keeps a lower limit that is already set.
+    pub fn cap_steps(self, step_limit: usize) -> Self {
+        let step_limit = self
+            .step_limit
+            .map_or(step_limit, |limit| limit.min(step_limit));
+        self.step_limit(step_limit)
+    }
+
+    /// Records the initial state, every `n`th step and the final state.
+    pub fn record_every(mut self, n: usize) -> Self {
+        assert!(n > 0, "can't record every 0th frame");
+        self.record_every = Some(n);
+        self
+    }
+
+    /// Steps `simulation` until it is done or the step limit is reached.
+    pub fn run<S: Simulation>(&self, simulation: &mut S) -> Run {
+        self.run_until(simulation, |_| false)
+    }
+
+    /// Steps `simulation` until `stop` matches its state, it is done or the step limit is
+    /// reached. The predicate is checked before every step, including the first.
+    pub fn run_until<S: Simulation>(
+        &self,
+        simulation: &mut S,
+        mut stop: impl FnMut(&S::State) -> bool,
+    ) -> Run {
+        let mut recording = Recording::default();
+        let mut steps = 0usize;
+
+        let reason = loop {
+            if let Some(n) = self.record_every {
+                if steps.is_multiple_of(n) {
+                    recording.frames.push(Frame {
+                        step: steps,
+                        grid: simulation.render(),
+                    });
+                }
+            }
+
+            if stop(simulation.state()) {
+                break Stop::Predicate;
+            }
+            if simulation.is_done() {
+                break Stop::Done;
+            }
+            if self.step_limit.is_some_and(|limit| steps >= limit) {
+                break Stop::StepLimit;
+            }
+
+            simulation.step();
+            steps += 1;
+        };
+
+        if self.record_every.is_some()
+            && recording
+                .frames
+                .last()
+                .is_none_or(|frame| frame.step != steps)
+        {
+            recording.frames.push(Frame {
+                step: steps,
+                grid: simulation.render(),
+            });
+        }
+
+        Run {
+            steps,
+            stop: reason,
+            recording,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A counter that is drawn as a row of filled cells and is done at `end`.
+    struct Counter {
+        value: usize,
+        end: usize,
+    }
+
+    impl Simulation for Counter {
+        type State = usize;
+
+        fn state(&self) -> &usize {
+            &self.value
+        }
+
+        fn step(&mut self) {
+            assert!(!Simulation::is_done(self));
+            self.value += 1;
+        }
+
+        fn is_done(&self) -> bool {
+            self.value == self.end
+        }
+
+        fn render(&self) -> Grid<char> {
+            let cells = (0..self.end)
+                .map(|idx| if idx < self.value { '#' } else { '.' })
+                .collect();
+            Grid::from_cells(self.end, cells)
+        }
+    }
+
+    #[test]
+    fn stops_for_the_first_reason() {
+        let run = Driver::new().run(&mut Counter { value: 0, end: 5 });
+        assert_eq!((run.steps, run.stop), (5, Stop::Done));
+        assert!(run.recording.is_empty());
+
+        let run = Driver::new()
+            .step_limit(3)
+            .run(&mut Counter { value: 0, end: 5 });
+        assert_eq!((run.steps, run.stop), (3, Stop::StepLimit));
+
+        let run = Driver::new()
+            .step_limit(3)
+            .run_until(&mut Counter { value: 0, end: 5 }, |&value| value == 2);
+        assert_eq!((run.steps, run.stop), (2, Stop::Predicate));
+
+        let run = Driver::new().run_until(&mut Counter { value: 0, end: 5 }, |_| true);
+        assert_eq!((run.steps, run.stop), (0, Stop::Predicate));
+    }
+
+    #[test]
+    fn records_and_replays_every_nth_frame() {
+        let run = Driver::new()
+            .record_every(2)
+            .run(&mut Counter { value: 0, end: 5 });
+
+        let mut replayed = Vec::new();
+        run.recording.replay(|frame| replayed.push(frame.step));
+        assert_eq!(replayed, vec![0, 2, 4, 5]);
+
+        let frame = run.recording.frame_at(3).unwrap();
+        assert_eq!(frame.step, 2);
+        assert_eq!(frame.to_string(), "step 2\n##...\n");
+    }
+}
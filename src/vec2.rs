@@ -0,0 +1,225 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset on a 2D grid. `x` grows to the right, `y` grows downwards.
+///
+/// The plain operators panic on overflow in debug builds like the integer operators do. Use the
+/// `checked_*` methods where positions can leave the valid range and the `wrapping_*` methods for
+/// grids that wrap around at their edges.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd, Default)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// Returns whether `0 <= x < bounds.x` and `0 <= y < bounds.y`.
+    pub fn is_within_bounds(&self, bounds: Vec2) -> bool {
+        self.x >= 0 && self.y >= 0 && self.x < bounds.x && self.y < bounds.y
+    }
+
+    /// Returns whether `0 <= x <= max.x` and `0 <= y <= max.y`.
+    pub fn is_within_bounds_inclusive(&self, max: Vec2) -> bool {
+        self.x >= 0 && self.y >= 0 && self.x <= max.x && self.y <= max.y
+    }
+
+    pub fn checked_add(&self, other: Vec2) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    pub fn checked_sub(&self, other: Vec2) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+        ))
+    }
+
+    pub fn checked_mul(&self, scalar: i64) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.x.checked_mul(scalar)?,
+            self.y.checked_mul(scalar)?,
+        ))
+    }
+
+    /// Adds `other`, returning `None` on overflow or if the result lies outside of `bounds`, see
+    /// [`Vec2::is_within_bounds`].
+    pub fn checked_bounded_add(&self, other: Vec2, bounds: Vec2) -> Option<Vec2> {
+        self.checked_add(other)
+            .filter(|sum| sum.is_within_bounds(bounds))
+    }
+
+    /// Adds `other` on a torus of size `bounds`, so the result always lies within `bounds`.
+    pub fn wrapping_add(&self, other: Vec2, bounds: Vec2) -> Vec2 {
+        Vec2::new(
+            (self.x + other.x).rem_euclid(bounds.x),
+            (self.y + other.y).rem_euclid(bounds.y),
+        )
+    }
+
+    /// Multiplies by `scalar` on a torus of size `bounds`, so the result always lies within
+    /// `bounds`.
+    pub fn wrapping_mul(&self, scalar: i64, bounds: Vec2) -> Vec2 {
+        Vec2::new(
+            (self.x * scalar).rem_euclid(bounds.x),
+            (self.y * scalar).rem_euclid(bounds.y),
+        )
+    }
+
+    /// Returns `n` if `self == other * n`, `None` if there is no such integer.
+    pub fn divided_by(&self, other: Vec2) -> Option<i64> {
+        if self.x.checked_rem(other.x)? != 0 || self.y.checked_rem(other.y)? != 0 {
+            return None;
+        }
+
+        let x_quot = self.x / other.x;
+        let y_quot = self.y / other.y;
+        (x_quot == y_quot).then_some(x_quot)
+    }
+
+    /// Number of orthogonal steps needed to get from `self` to `other`.
+    pub fn manhattan_distance(&self, other: Vec2) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Number of orthogonal or diagonal steps needed to get from `self` to `other`.
+    pub fn chebyshev_distance(&self, other: Vec2) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Rotates by 90° clockwise as seen on screen, e.g. up becomes right.
+    pub fn rotate_right(&self) -> Vec2 {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// Rotates by 90° counter-clockwise as seen on screen, e.g. up becomes left.
+    pub fn rotate_left(&self) -> Vec2 {
+        Vec2::new(self.y, -self.x)
+    }
+}
+
+impl Debug for Vec2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(x={}, y={})", self.x, self.y)
+    }
+}
+
+impl Display for Vec2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vec2 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec2 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Vec2 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Self::Output {
+        Vec2 {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounded_and_wrapping_arithmetic() {
+        let bounds = Vec2::new(4, 3);
+        let pos = Vec2::new(3, 2);
+
+        assert_eq!(
+            Vec2::ZERO.checked_bounded_add(pos, bounds),
+            Some(Vec2::new(3, 2))
+        );
+        assert_eq!(pos.checked_bounded_add(Vec2::new(1, 0), bounds), None);
+        assert_eq!(Vec2::new(i64::MAX, 0).checked_add(Vec2::new(1, 0)), None);
+        assert_eq!(pos.wrapping_add(Vec2::new(2, -4), bounds), Vec2::new(1, 1));
+        assert_eq!(Vec2::new(-1, 2).wrapping_mul(3, bounds), Vec2::new(1, 0));
+    }
+
+    #[test]
+    fn divided_by() {
+        assert_eq!(Vec2::new(6, 9).divided_by(Vec2::new(2, 3)), Some(3));
+        assert_eq!(Vec2::new(6, 8).divided_by(Vec2::new(2, 3)), None);
+        assert_eq!(Vec2::new(6, 6).divided_by(Vec2::new(2, 3)), None);
+        assert_eq!(Vec2::new(6, 9).divided_by(Vec2::new(0, 3)), None);
+    }
+
+    #[test]
+    fn distances_and_rotation() {
+        let a = Vec2::new(1, -2);
+        let b = Vec2::new(-3, 4);
+
+        assert_eq!(a.manhattan_distance(b), 10);
+        assert_eq!(a.chebyshev_distance(b), 6);
+
+        let up = Vec2::new(0, -1);
+        assert_eq!(up.rotate_right(), Vec2::new(1, 0));
+        assert_eq!(up.rotate_left(), Vec2::new(-1, 0));
+        assert_eq!(up.rotate_right().rotate_right(), -up);
+        assert_eq!(a.rotate_left().rotate_right(), a);
+    }
+
+    #[test]
+    fn formatting() {
+        let v = Vec2::new(3, -7);
+        assert_eq!(format!("{v:?}"), "(x=3, y=-7)");
+        assert_eq!(v.to_string(), "3,-7");
+    }
+}